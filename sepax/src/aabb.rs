@@ -129,12 +129,24 @@ impl crate::Shape for AABB
 
 }
 
+impl crate::Distance for AABB
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return crate::polygon_boundary(self.position, &self.points(), point);
+
+    }
+
+}
+
 #[cfg(test)]
 mod aabb_tests
 {
 
     use super::*;
-    use crate::{float_equal, Shape};
+    use crate::{float_equal, Shape, Distance};
 
     #[test]
     fn test_num_axes()
@@ -191,4 +203,26 @@ mod aabb_tests
 
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_boundary()
+    {
+
+        let aabb = AABB::new((1.0, 2.0), 4.0, 2.0);
+
+        let (point, normal, distance) = aabb.boundary((2.0, 2.5));
+
+        assert!(float_equal(point.0, 2.0));
+        assert!(float_equal(point.1, 2.0));
+        assert!(float_equal(normal.0, 0.0));
+        assert!(float_equal(normal.1, -1.0));
+        assert!(float_equal(distance, -0.5));
+
+        let (_, normal, distance) = aabb.boundary((8.0, 3.0));
+
+        assert!(float_equal(normal.0, 1.0));
+        assert!(float_equal(normal.1, 0.0));
+        assert!(float_equal(distance, 3.0));
+
+    }
+
+}
@@ -0,0 +1,614 @@
+//! Tests one shape against many AABBs or circles at once. The batches store their shapes
+//! as a structure of arrays, so that the bulk of the work is a handful of tight loops over
+//! plain `f32` slices which the compiler can auto-vectorize.
+//!
+//! Every query runs in two phases: first all shapes in the batch are tested against the
+//! axes which do not depend on the other shape (the X and Y axes and the fixed axes of the
+//! query shape), and then the few shapes that survive are checked exactly with `sat_overlap`
+//! where a closest-point axis is still needed.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::batch::AABBBatch;
+//!
+//! let mut bullets = AABBBatch::new();
+//! bullets.push(&AABB::new((0.0, 0.0), 1.0, 1.0));
+//! bullets.push(&AABB::new((10.0, 0.0), 1.0, 1.0));
+//! bullets.push(&AABB::new((2.5, 0.5), 1.0, 1.0));
+//!
+//! let enemy = Circle::new((2.0, 0.5), 1.5);
+//!
+//! let mut hits = Vec::new();
+//! bullets.overlaps(&enemy, &mut hits);
+//!
+//! assert_eq!(hits, vec![0, 2]);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Shape;
+use crate::aabb::AABB;
+use crate::circle::Circle;
+use crate::math;
+
+/// A batch of AABBs stored as a structure of arrays. All four vectors always have the same length.
+#[derive(Clone, Debug, Default)]
+pub struct AABBBatch
+{
+
+    pub x: Vec<f32>,
+    pub y: Vec<f32>,
+    pub width: Vec<f32>,
+    pub height: Vec<f32>
+
+}
+
+/// A batch of circles stored as a structure of arrays. All three vectors always have the same length.
+#[derive(Clone, Debug, Default)]
+pub struct CircleBatch
+{
+
+    pub x: Vec<f32>,
+    pub y: Vec<f32>,
+    pub radius: Vec<f32>
+
+}
+
+impl AABBBatch
+{
+
+    /// Create an empty batch.
+    pub fn new() -> AABBBatch
+    {
+
+        return AABBBatch::default();
+
+    }
+
+    /// Create an empty batch with room for the given number of AABBs.
+    pub fn with_capacity(capacity: usize) -> AABBBatch
+    {
+
+        return AABBBatch { x: Vec::with_capacity(capacity), y: Vec::with_capacity(capacity), width: Vec::with_capacity(capacity), height: Vec::with_capacity(capacity) };
+
+    }
+
+    /// Add an AABB to the end of the batch. Its index is the previous length of the batch.
+    pub fn push(&mut self, aabb: &AABB)
+    {
+
+        self.x.push(aabb.position.0);
+        self.y.push(aabb.position.1);
+        self.width.push(aabb.width);
+        self.height.push(aabb.height);
+
+    }
+
+    /// Access the AABB at the given index.
+    pub fn get(&self, index: usize) -> AABB
+    {
+
+        return AABB::new((self.x[index], self.y[index]), self.width[index], self.height[index]);
+
+    }
+
+    /// The number of AABBs in the batch.
+    pub fn len(&self) -> usize
+    {
+
+        return self.x.len();
+
+    }
+
+    /// Returns true if the batch contains no AABBs.
+    pub fn is_empty(&self) -> bool
+    {
+
+        return self.x.is_empty();
+
+    }
+
+    /// Remove all AABBs from the batch, keeping the allocated memory.
+    pub fn clear(&mut self)
+    {
+
+        self.x.clear();
+        self.y.clear();
+        self.width.clear();
+        self.height.clear();
+
+    }
+
+    fn candidates(&self, shape: &(impl Shape + ?Sized)) -> Vec<bool>
+    {
+
+        let length = self.len();
+        let (x, y, width, height) = (&self.x[..length], &self.y[..length], &self.width[..length], &self.height[..length]);
+
+        let mut candidates = vec![true; length];
+
+        for axis in fixed_axes(shape)
+        {
+
+            let (min_s, max_s) = shape.project(axis, false);
+
+            for i in 0..length
+            {
+
+                //The corners of the box extend from its position by the width and height
+                //along the axis, so the extremes are found without looping over the corners
+                let base = (x[i] * axis.0) + (y[i] * axis.1);
+                let u = width[i] * axis.0;
+                let v = height[i] * axis.1;
+
+                let min = base + f32::min(u, 0.0) + f32::min(v, 0.0);
+                let max = base + f32::max(u, 0.0) + f32::max(v, 0.0);
+
+                candidates[i] &= !crate::separated((min, max), (min_s, max_s));
+
+            }
+
+        }
+
+        //The box axes are included in the fixed axes, so the test is exact unless the shape
+        //also needs to test axes towards the closest corner of each box
+        if (0..shape.num_axes()).any(|i| shape.needs_closest(i))
+        {
+
+            for (i, candidate) in candidates.iter_mut().enumerate()
+            {
+
+                if *candidate
+                {
+
+                    *candidate = crate::sat_overlap(shape, &self.get(i));
+
+                }
+
+            }
+
+        }
+
+        return candidates;
+
+    }
+
+    /// Collects the indices of all AABBs in the batch which overlap the given shape into hits,
+    /// in increasing order. Gives the same result as calling `sat_overlap` for each AABB.
+    pub fn overlaps(&self, shape: &(impl Shape + ?Sized), hits: &mut Vec<usize>)
+    {
+
+        hits.clear();
+        hits.extend(self.candidates(shape).iter().enumerate().filter(|(_i, hit)| **hit).map(|(i, _hit)| i));
+
+    }
+
+    /// Sets bit `i % 64` of word `i / 64` in the mask if the AABB at index `i` overlaps the
+    /// given shape. The mask is resized to fit the batch.
+    pub fn overlap_mask(&self, shape: &(impl Shape + ?Sized), mask: &mut Vec<u64>)
+    {
+
+        to_mask(&self.candidates(shape), mask);
+
+    }
+
+}
+
+impl CircleBatch
+{
+
+    /// Create an empty batch.
+    pub fn new() -> CircleBatch
+    {
+
+        return CircleBatch::default();
+
+    }
+
+    /// Create an empty batch with room for the given number of circles.
+    pub fn with_capacity(capacity: usize) -> CircleBatch
+    {
+
+        return CircleBatch { x: Vec::with_capacity(capacity), y: Vec::with_capacity(capacity), radius: Vec::with_capacity(capacity) };
+
+    }
+
+    /// Add a circle to the end of the batch. Its index is the previous length of the batch.
+    pub fn push(&mut self, circle: &Circle)
+    {
+
+        self.x.push(circle.position.0);
+        self.y.push(circle.position.1);
+        self.radius.push(circle.radius);
+
+    }
+
+    /// Access the circle at the given index.
+    pub fn get(&self, index: usize) -> Circle
+    {
+
+        return Circle::new((self.x[index], self.y[index]), self.radius[index]);
+
+    }
+
+    /// The number of circles in the batch.
+    pub fn len(&self) -> usize
+    {
+
+        return self.x.len();
+
+    }
+
+    /// Returns true if the batch contains no circles.
+    pub fn is_empty(&self) -> bool
+    {
+
+        return self.x.is_empty();
+
+    }
+
+    /// Remove all circles from the batch, keeping the allocated memory.
+    pub fn clear(&mut self)
+    {
+
+        self.x.clear();
+        self.y.clear();
+        self.radius.clear();
+
+    }
+
+    fn candidates(&self, shape: &(impl Shape + ?Sized)) -> Vec<bool>
+    {
+
+        let length = self.len();
+        let (x, y, radius) = (&self.x[..length], &self.y[..length], &self.radius[..length]);
+
+        let mut candidates = vec![true; length];
+
+        for axis in fixed_axes(shape)
+        {
+
+            let (min_s, max_s) = shape.project(axis, false);
+            let magnitude = math::sqrt((axis.0 * axis.0) + (axis.1 * axis.1));
+
+            for i in 0..length
+            {
+
+                let center = (x[i] * axis.0) + (y[i] * axis.1);
+                let extent = radius[i] * magnitude;
+
+                candidates[i] &= !crate::separated((center - extent, center + extent), (min_s, max_s));
+
+            }
+
+        }
+
+        //Every circle still has to test the axis towards the closest point of the shape
+        for (i, candidate) in candidates.iter_mut().enumerate()
+        {
+
+            if *candidate
+            {
+
+                *candidate = crate::sat_overlap(shape, &self.get(i));
+
+            }
+
+        }
+
+        return candidates;
+
+    }
+
+    /// Collects the indices of all circles in the batch which overlap the given shape into hits,
+    /// in increasing order. Gives the same result as calling `sat_overlap` for each circle.
+    pub fn overlaps(&self, shape: &(impl Shape + ?Sized), hits: &mut Vec<usize>)
+    {
+
+        hits.clear();
+        hits.extend(self.candidates(shape).iter().enumerate().filter(|(_i, hit)| **hit).map(|(i, _hit)| i));
+
+    }
+
+    /// Sets bit `i % 64` of word `i / 64` in the mask if the circle at index `i` overlaps the
+    /// given shape. The mask is resized to fit the batch.
+    pub fn overlap_mask(&self, shape: &(impl Shape + ?Sized), mask: &mut Vec<u64>)
+    {
+
+        to_mask(&self.candidates(shape), mask);
+
+    }
+
+}
+
+//The X and Y axes, followed by every axis of the shape which does not depend on the other shape
+fn fixed_axes(shape: &(impl Shape + ?Sized)) -> Vec<(f32, f32)>
+{
+
+    let mut axes = vec![(1.0, 0.0), (0.0, 1.0)];
+
+    for i in 0..shape.num_axes()
+    {
+
+        if !shape.needs_closest(i)
+        {
+
+            axes.push(shape.get_axis(i, (0.0, 0.0)));
+
+        }
+
+    }
+
+    return axes;
+
+}
+
+fn to_mask(candidates: &[bool], mask: &mut Vec<u64>)
+{
+
+    mask.clear();
+    mask.resize(candidates.len().div_ceil(64), 0);
+
+    for (i, hit) in candidates.iter().enumerate()
+    {
+
+        mask[i / 64] |= (*hit as u64) << (i % 64);
+
+    }
+
+}
+
+#[cfg(test)]
+mod batch_tests
+{
+
+    use super::*;
+    use crate::prelude::*;
+
+    //Deterministic pseudo-random numbers in [0, 1), so that the tests do not need a dependency
+    fn random(seed: &mut u32) -> f32
+    {
+
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 17;
+        *seed ^= *seed << 5;
+
+        return (*seed % 10000) as f32 / 10000.0;
+
+    }
+
+    fn bullets(count: usize) -> AABBBatch
+    {
+
+        let mut seed = 12345;
+        let mut batch = AABBBatch::with_capacity(count);
+
+        for _i in 0..count
+        {
+
+            batch.push(&AABB::new((random(&mut seed) * 512.0 - 256.0, random(&mut seed) * 384.0 - 192.0), 2.0, 2.0));
+
+        }
+
+        return batch;
+
+    }
+
+    fn circles(count: usize) -> CircleBatch
+    {
+
+        let mut seed = 54321;
+        let mut batch = CircleBatch::with_capacity(count);
+
+        for _i in 0..count
+        {
+
+            batch.push(&Circle::new((random(&mut seed) * 512.0 - 256.0, random(&mut seed) * 384.0 - 192.0), 1.0 + random(&mut seed) * 3.0));
+
+        }
+
+        return batch;
+
+    }
+
+    fn targets() -> Vec<Collider>
+    {
+
+        let mut gram = Parallelogram::rectangle((-30.0, 10.0), 60.0, 8.0);
+        gram.rotate(0.6);
+
+        return vec!
+        [
+
+            Collider::from(AABB::new((-16.0, -16.0), 32.0, 32.0)),
+            Collider::from(Circle::new((40.0, 20.0), 24.0)),
+            Collider::from(Capsule::new((-60.0, -40.0), (20.0, 10.0), 8.0)),
+            Collider::from(gram),
+            Collider::from(Polygon::from_vertices((100.0, 50.0), vec![(0.0, 30.0), (-25.0, -20.0), (25.0, -20.0)])),
+            Collider::from(Point::new((2.0, 1.0))),
+            Collider::from(Segment::new((4.0, 3.0), (5.0, 3.0)))
+
+        ];
+
+    }
+
+    #[test]
+    fn test_aabb_batch_matches_sat_overlap()
+    {
+
+        let mut batch = bullets(2000);
+        let mut hits = Vec::new();
+
+        //The point and segment only ever touch these, which counts as overlapping
+        batch.push(&AABB::new((0.0, 0.0), 2.0, 2.0));
+        batch.push(&AABB::new((4.0, 1.0), 1.0, 2.0));
+
+        for target in targets().iter()
+        {
+
+            batch.overlaps(target, &mut hits);
+
+            let expected: Vec<usize> = (0..batch.len()).filter(|i| sat_overlap(target, &batch.get(*i))).collect();
+
+            assert!(!expected.is_empty());
+            assert_eq!(hits, expected);
+
+        }
+
+    }
+
+    #[test]
+    fn test_circle_batch_matches_sat_overlap()
+    {
+
+        let mut batch = circles(2000);
+        let mut hits = Vec::new();
+
+        //The point and segment only ever touch these, which counts as overlapping
+        batch.push(&Circle::new((3.0, 1.0), 1.0));
+        batch.push(&Circle::new((4.5, 4.0), 1.0));
+
+        for target in targets().iter()
+        {
+
+            batch.overlaps(target, &mut hits);
+
+            let expected: Vec<usize> = (0..batch.len()).filter(|i| sat_overlap(target, &batch.get(*i))).collect();
+
+            assert!(!expected.is_empty());
+            assert_eq!(hits, expected);
+
+        }
+
+    }
+
+    #[test]
+    fn test_overlap_mask()
+    {
+
+        let mut batch = AABBBatch::new();
+
+        for i in 0..70
+        {
+
+            batch.push(&AABB::new((i as f32 * 2.0, 0.0), 1.0, 1.0));
+
+        }
+
+        let mut mask = Vec::new();
+        batch.overlap_mask(&AABB::new((-0.5, 0.0), 3.0, 1.0), &mut mask);
+
+        assert_eq!(mask, vec![0b11, 0]);
+
+        batch.overlap_mask(&AABB::new((129.5, 0.0), 3.0, 1.0), &mut mask);
+
+        assert_eq!(mask, vec![0, 0b110]);
+
+        batch.clear();
+        batch.overlap_mask(&AABB::new((0.0, 0.0), 3.0, 1.0), &mut mask);
+
+        assert!(batch.is_empty());
+        assert!(mask.is_empty());
+
+    }
+
+    //Run with `cargo test --release -- --ignored --nocapture bench_` to compare the batch
+    //queries with calling sat_overlap in a loop
+    #[test]
+    #[ignore]
+    fn bench_aabb_batch()
+    {
+
+        let batch = bullets(10000);
+        let shapes: Vec<AABB> = (0..batch.len()).map(|i| batch.get(i)).collect();
+        let targets = targets();
+
+        let iterations = 200;
+        let mut hits = Vec::new();
+        let mut count = 0;
+
+        let start = std::time::Instant::now();
+        for _i in 0..iterations
+        {
+
+            for target in targets.iter()
+            {
+
+                count += shapes.iter().filter(|shape| sat_overlap(target, *shape)).count();
+
+            }
+
+        }
+        let looped = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for _i in 0..iterations
+        {
+
+            for target in targets.iter()
+            {
+
+                batch.overlaps(target, &mut hits);
+                count -= hits.len();
+
+            }
+
+        }
+        let batched = start.elapsed();
+
+        assert_eq!(count, 0);
+        println!("10000 AABBs against {} targets: loop {:?}, batch {:?} ({:.1}x)", targets.len(), looped / iterations, batched / iterations, looped.as_secs_f64() / batched.as_secs_f64());
+
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_circle_batch()
+    {
+
+        let batch = circles(10000);
+        let shapes: Vec<Circle> = (0..batch.len()).map(|i| batch.get(i)).collect();
+        let targets = targets();
+
+        let iterations = 200;
+        let mut hits = Vec::new();
+        let mut count = 0;
+
+        let start = std::time::Instant::now();
+        for _i in 0..iterations
+        {
+
+            for target in targets.iter()
+            {
+
+                count += shapes.iter().filter(|shape| sat_overlap(target, *shape)).count();
+
+            }
+
+        }
+        let looped = start.elapsed();
+
+        let start = std::time::Instant::now();
+        for _i in 0..iterations
+        {
+
+            for target in targets.iter()
+            {
+
+                batch.overlaps(target, &mut hits);
+                count -= hits.len();
+
+            }
+
+        }
+        let batched = start.elapsed();
+
+        assert_eq!(count, 0);
+        println!("10000 circles against {} targets: loop {:?}, batch {:?} ({:.1}x)", targets.len(), looped / iterations, batched / iterations, looped.as_secs_f64() / batched.as_secs_f64());
+
+    }
+
+}
@@ -0,0 +1,414 @@
+//! Boolean operations and clipping for convex polygons. Every operation returns its result
+//! as a set of convex, non-overlapping polygons with counterclockwise winding, so the pieces
+//! can be used for SAT collisions directly.
+//!
+//! The resulting polygons share the `position` of the first polygon passed in, with their
+//! vertices relative to it.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::boolean::{difference, intersection};
+//!
+//! let wall = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)].into());
+//! let hole = Polygon::from_vertices((2.0, 4.0), [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].into());
+//!
+//! let pieces = difference(&wall, &hole);
+//!
+//! assert!(pieces.iter().all(|piece| piece.is_convex()));
+//! assert!(pieces.iter().all(|piece| intersection(piece, &hole).is_empty()));
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::aabb::AABB;
+use crate::polygon::{Polygon, signed_area};
+
+/// Returns the region covered by both polygons. The result is either empty or a single
+/// polygon. Requires both polygons to be convex.
+pub fn intersection(left: &Polygon, right: &Polygon) -> Vec<Polygon>
+{
+
+    let mut result = Vec::new();
+
+    let mut remaining = counterclockwise(&left.vertices);
+    let cutter = relative_to(right, left.position);
+
+    for i in 0..cutter.len()
+    {
+
+        let start = cutter[i];
+        let end = cutter[(i + 1) % cutter.len()];
+
+        remaining = clip(&remaining, start, (end.0 - start.0, end.1 - start.1));
+
+    }
+
+    push_piece(&mut result, left.position, remaining);
+
+    return result;
+
+}
+
+/// Returns the region covered by the left polygon but not the right one, as a set of
+/// convex pieces. Requires both polygons to be convex.
+pub fn difference(left: &Polygon, right: &Polygon) -> Vec<Polygon>
+{
+
+    let mut result = Vec::new();
+
+    let mut remaining = counterclockwise(&left.vertices);
+    let cutter = relative_to(right, left.position);
+
+    if cutter.len() < 3
+    {
+
+        push_piece(&mut result, left.position, remaining);
+        return result;
+
+    }
+
+    //Everything outside an edge of the cutter is kept, and the rest is carried on to the
+    //next edge. This peels off disjoint convex pieces until only the overlap remains.
+    for i in 0..cutter.len()
+    {
+
+        let start = cutter[i];
+        let end = cutter[(i + 1) % cutter.len()];
+        let direction = (end.0 - start.0, end.1 - start.1);
+
+        push_piece(&mut result, left.position, clip(&remaining, start, (-direction.0, -direction.1)));
+        remaining = clip(&remaining, start, direction);
+
+        if remaining.len() < 3
+        {
+
+            break;
+
+        }
+
+    }
+
+    return result;
+
+}
+
+/// Returns the region covered by either polygon, as a set of convex pieces. The left polygon
+/// is always the first piece. Requires both polygons to be convex.
+pub fn union(left: &Polygon, right: &Polygon) -> Vec<Polygon>
+{
+
+    let mut result = Vec::new();
+    push_piece(&mut result, left.position, counterclockwise(&left.vertices));
+
+    let mut right = right.clone();
+    right.vertices = relative_to(&right, left.position);
+    right.position = left.position;
+
+    result.extend(difference(&right, left));
+
+    return result;
+
+}
+
+/// Returns the part of the polygon to the left of the infinite line located at
+/// line_position pointing in the direction of the vector line_direction, where left
+/// is counterclockwise from the direction when the Y-axis points up. Requires the
+/// polygon to be convex.
+pub fn clip_line(polygon: &Polygon, line_position: (f32, f32), line_direction: (f32, f32)) -> Option<Polygon>
+{
+
+    let start = (line_position.0 - polygon.position.0, line_position.1 - polygon.position.1);
+    let clipped = clip(&counterclockwise(&polygon.vertices), start, line_direction);
+
+    return piece(polygon.position, clipped);
+
+}
+
+/// Splits the polygon along the given line, returning the parts to the left and right
+/// of it in that order. Requires the polygon to be convex.
+pub fn split_line(polygon: &Polygon, line_position: (f32, f32), line_direction: (f32, f32)) -> (Option<Polygon>, Option<Polygon>)
+{
+
+    return (clip_line(polygon, line_position, line_direction), clip_line(polygon, line_position, (-line_direction.0, -line_direction.1)));
+
+}
+
+/// Returns the part of the polygon inside the given AABB. Requires the polygon to
+/// be convex.
+pub fn clip_aabb(polygon: &Polygon, aabb: &AABB) -> Option<Polygon>
+{
+
+    let rectangle = Polygon::from_vertices(aabb.position, vec![(0.0, 0.0), (aabb.width, 0.0), (aabb.width, aabb.height), (0.0, aabb.height)]);
+
+    return intersection(polygon, &rectangle).pop();
+
+}
+
+//Sutherland-Hodgman: keep the part of the convex polygon to the left of the directed line
+fn clip(vertices: &[(f32, f32)], start: (f32, f32), direction: (f32, f32)) -> Vec<(f32, f32)>
+{
+
+    let mut result = Vec::with_capacity(vertices.len() + 1);
+
+    if vertices.is_empty()
+    {
+
+        return result;
+
+    }
+
+    let side = |point: (f32, f32)| (direction.0 * (point.1 - start.1)) - (direction.1 * (point.0 - start.0));
+
+    let mut previous = vertices[vertices.len() - 1];
+    let mut previous_side = side(previous);
+
+    for vertex in vertices.iter()
+    {
+
+        let current_side = side(*vertex);
+
+        if (current_side >= 0.0) != (previous_side >= 0.0)
+        {
+
+            let t = previous_side / (previous_side - current_side);
+            result.push((previous.0 + (vertex.0 - previous.0) * t, previous.1 + (vertex.1 - previous.1) * t));
+
+        }
+
+        if current_side >= 0.0
+        {
+
+            result.push(*vertex);
+
+        }
+
+        previous = *vertex;
+        previous_side = current_side;
+
+    }
+
+    return result;
+
+}
+
+fn counterclockwise(vertices: &[(f32, f32)]) -> Vec<(f32, f32)>
+{
+
+    let mut result = vertices.to_vec();
+
+    if signed_area(&result) < 0.0
+    {
+
+        result.reverse();
+
+    }
+
+    return result;
+
+}
+
+//The vertices of the polygon, counterclockwise and relative to the given position
+fn relative_to(polygon: &Polygon, position: (f32, f32)) -> Vec<(f32, f32)>
+{
+
+    let offset = (polygon.position.0 - position.0, polygon.position.1 - position.1);
+
+    return counterclockwise(&polygon.vertices).iter().map(|(x, y)| (x + offset.0, y + offset.1)).collect();
+
+}
+
+fn piece(position: (f32, f32), mut vertices: Vec<(f32, f32)>) -> Option<Polygon>
+{
+
+    //Clipping against several edges through the same corner leaves duplicate vertices behind
+    vertices.dedup_by(|a, b| (a.0 - b.0).abs() < f32::EPSILON && (a.1 - b.1).abs() < f32::EPSILON);
+
+    while vertices.len() > 1 && (vertices[0].0 - vertices[vertices.len() - 1].0).abs() < f32::EPSILON && (vertices[0].1 - vertices[vertices.len() - 1].1).abs() < f32::EPSILON
+    {
+
+        vertices.pop();
+
+    }
+
+    if vertices.len() < 3 || signed_area(&vertices) < f32::EPSILON
+    {
+
+        return None;
+
+    }
+
+    return Some(Polygon::from_vertices(position, vertices));
+
+}
+
+fn push_piece(result: &mut Vec<Polygon>, position: (f32, f32), vertices: Vec<(f32, f32)>)
+{
+
+    if let Some(polygon) = piece(position, vertices)
+    {
+
+        result.push(polygon);
+
+    }
+
+}
+
+#[cfg(test)]
+mod boolean_tests
+{
+
+    use super::*;
+    use crate::{float_equal, contains_point};
+
+    fn area(polygons: &[Polygon]) -> f32
+    {
+
+        return polygons.iter().map(|polygon| signed_area(&polygon.vertices)).sum();
+
+    }
+
+    fn square(position: (f32, f32), size: f32) -> Polygon
+    {
+
+        return Polygon::from_vertices(position, vec![(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)]);
+
+    }
+
+    #[test]
+    fn test_intersection()
+    {
+
+        let left = square((0.0, 0.0), 4.0);
+        let right = square((2.0, 1.0), 4.0);
+
+        let result = intersection(&left, &right);
+
+        assert_eq!(result.len(), 1);
+        assert!(float_equal(area(&result), 6.0));
+        assert!(result[0].is_convex());
+        assert!(contains_point(&result[0], (3.0, 2.0)));
+        assert!(!contains_point(&result[0], (1.0, 2.0)));
+
+        let far = square((10.0, 10.0), 1.0);
+        assert!(intersection(&left, &far).is_empty());
+
+    }
+
+    #[test]
+    fn test_intersection_clockwise()
+    {
+
+        let left = Polygon::from_vertices((0.0, 0.0), vec![(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)]);
+        let triangle = Polygon::from_vertices((2.0, 2.0), vec![(0.0, 4.0), (4.0, -4.0), (-4.0, -4.0)]);
+
+        let result = intersection(&left, &triangle);
+
+        assert_eq!(result.len(), 1);
+        assert!(signed_area(&result[0].vertices) > 0.0);
+        assert!(result[0].is_convex());
+
+    }
+
+    #[test]
+    fn test_difference()
+    {
+
+        let wall = square((0.0, 0.0), 4.0);
+        let hole = square((1.0, 1.0), 2.0);
+
+        let pieces = difference(&wall, &hole);
+
+        assert!(float_equal(area(&pieces), 12.0));
+        assert!(pieces.iter().all(|piece| piece.is_convex()));
+        assert!(pieces.iter().all(|piece| intersection(piece, &hole).is_empty()));
+        assert!(pieces.iter().any(|piece| contains_point(piece, (0.5, 0.5))));
+        assert!(pieces.iter().any(|piece| contains_point(piece, (3.5, 3.5))));
+
+        //Pieces may share edges, but must not overlap each other
+        for i in 0..pieces.len()
+        {
+
+            for j in (i + 1)..pieces.len()
+            {
+
+                assert!(intersection(&pieces[i], &pieces[j]).is_empty());
+
+            }
+
+        }
+
+    }
+
+    #[test]
+    fn test_difference_disjoint_and_covered()
+    {
+
+        let left = square((0.0, 0.0), 2.0);
+        let far = square((5.0, 5.0), 2.0);
+        let cover = square((-1.0, -1.0), 4.0);
+
+        let untouched = difference(&left, &far);
+
+        assert_eq!(untouched.len(), 1);
+        assert!(float_equal(area(&untouched), 4.0));
+        assert!(difference(&left, &cover).is_empty());
+
+    }
+
+    #[test]
+    fn test_union()
+    {
+
+        let left = square((0.0, 0.0), 2.0);
+        let right = square((1.0, 1.0), 2.0);
+
+        let pieces = union(&left, &right);
+
+        assert!(float_equal(area(&pieces), 7.0));
+        assert!(pieces.iter().all(|piece| piece.position == (0.0, 0.0)));
+        assert!(pieces.iter().any(|piece| contains_point(piece, (2.5, 2.5))));
+
+    }
+
+    #[test]
+    fn test_clip_line()
+    {
+
+        let square = square((0.0, 0.0), 2.0);
+
+        let left = clip_line(&square, (1.0, 0.0), (0.0, 1.0)).unwrap();
+
+        assert!(float_equal(area(std::slice::from_ref(&left)), 2.0));
+        assert!(contains_point(&left, (0.5, 1.0)));
+        assert!(!contains_point(&left, (1.5, 1.0)));
+
+        assert!(clip_line(&square, (5.0, 0.0), (0.0, -1.0)).is_none());
+
+        let (top, bottom) = split_line(&square, (0.0, 1.5), (1.0, 0.0));
+
+        assert!(float_equal(area(&[top.unwrap()]), 1.0));
+        assert!(float_equal(area(&[bottom.unwrap()]), 3.0));
+
+    }
+
+    #[test]
+    fn test_clip_aabb()
+    {
+
+        let triangle = Polygon::from_vertices((0.0, 0.0), vec![(-4.0, -4.0), (4.0, -4.0), (0.0, 4.0)]);
+        let screen = AABB::new((-2.0, -2.0), 4.0, 4.0);
+
+        let visible = clip_aabb(&triangle, &screen).unwrap();
+
+        assert!(visible.is_convex());
+        assert!(visible.vertices.iter().all(|(x, y)| x.abs() <= 2.0 + 0.0001 && y.abs() <= 2.0 + 0.0001));
+        assert!(float_equal(area(&[visible]), 14.0));
+
+        assert!(clip_aabb(&triangle, &AABB::new((10.0, 10.0), 1.0, 1.0)).is_none());
+
+    }
+
+}
@@ -0,0 +1,783 @@
+//! A sweep-and-prune broadphase, which finds the pairs of shapes whose bounds overlap
+//! without testing every pair.
+//!
+//! The bounds of every shape are projected onto one axis, and the endpoints are kept in
+//! a sorted list between updates. When shapes only move a little between updates the list
+//! stays almost sorted, so an insertion sort restores the order in close to linear time.
+//!
+//! Two shapes only start or stop overlapping along the axis when the sort swaps the lower
+//! end of one with the upper end of the other, so pairs are added and removed right at those
+//! swaps. Pairs which overlap along the axis are checked along the other axis again only when
+//! one of their shapes has moved. Every change is reported as a `PairEvent`.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::broadphase::{SweepAndPrune, SweepAxis, PairEvent};
+//!
+//! let mut broadphase = SweepAndPrune::new(SweepAxis::Y);
+//!
+//! let mut enemy = Circle::new((0.0, 10.0), 2.0);
+//! let bullet = AABB::new((-0.5, 0.0), 1.0, 1.0);
+//!
+//! let enemy_proxy = broadphase.insert(&enemy);
+//! let bullet_proxy = broadphase.insert(&bullet);
+//!
+//! broadphase.update();
+//! assert!(broadphase.drain_events().next().is_none());
+//!
+//! enemy.position = (0.0, 2.0);
+//! broadphase.set(enemy_proxy, &enemy);
+//! broadphase.update();
+//!
+//! let events: Vec<PairEvent> = broadphase.drain_events().collect();
+//! assert_eq!(events, vec![PairEvent::Added(enemy_proxy, bullet_proxy)]);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::Shape;
+use crate::aabb::AABB;
+
+/// The axis along which the endpoints are sorted. Pick the axis along which the shapes
+/// are spread out the most, or let `SweepAxis::Auto` pick it on every update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SweepAxis
+{
+
+    X,
+    Y,
+    Auto
+
+}
+
+/// A change in the set of overlapping pairs. The proxies in a pair are always in
+/// increasing order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PairEvent
+{
+
+    Added(usize, usize),
+    Removed(usize, usize)
+
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Endpoint
+{
+
+    value: f32,
+    proxy: usize,
+    is_min: bool
+
+}
+
+/// The sweep-and-prune broadphase. Shapes are registered with `insert`, which returns a
+/// proxy used to refer to the shape later.
+#[derive(Clone, Debug)]
+pub struct SweepAndPrune
+{
+
+    axis: SweepAxis,
+    sorted_along_x: bool,
+    bounds: Vec<Option<AABB>>,
+    free: Vec<usize>,
+    endpoints: Vec<Endpoint>,
+    //Endpoints added since the last update, which still have to be sorted into place
+    inserted: usize,
+    moved: Vec<bool>,
+    //Pairs which overlap along the sweep axis, and whether they also overlap across it
+    pairs: BTreeMap<(usize, usize), bool>,
+    events: Vec<PairEvent>
+
+}
+
+/// Returns the axis-aligned bounding box of the given shape, using its projections
+/// onto the X and Y axes.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+/// use sepax2d::broadphase::bounds;
+///
+/// let circle = Circle::new((1.0, 2.0), 3.0);
+/// let aabb = bounds(&circle);
+///
+/// assert_eq!(aabb.position, (-2.0, -1.0));
+/// assert_eq!((aabb.width, aabb.height), (6.0, 6.0));
+/// ```
+pub fn bounds(shape: &(impl Shape + ?Sized)) -> AABB
+{
+
+    let (min_x, max_x) = shape.project((1.0, 0.0), true);
+    let (min_y, max_y) = shape.project((0.0, 1.0), true);
+
+    return AABB::new((min_x, min_y), max_x - min_x, max_y - min_y);
+
+}
+
+impl SweepAndPrune
+{
+
+    /// Create an empty broadphase sorting along the given axis.
+    pub fn new(axis: SweepAxis) -> SweepAndPrune
+    {
+
+        return SweepAndPrune { axis, sorted_along_x: axis != SweepAxis::Y, bounds: Vec::new(), free: Vec::new(), endpoints: Vec::new(), inserted: 0, moved: Vec::new(), pairs: BTreeMap::new(), events: Vec::new() };
+
+    }
+
+    /// Register a shape and return its proxy. The shape only takes part in pairs from the
+    /// next call to `update`. Proxies of removed shapes are reused.
+    pub fn insert(&mut self, shape: &(impl Shape + ?Sized)) -> usize
+    {
+
+        return self.insert_bounds(bounds(shape));
+
+    }
+
+    /// Register a bounding box directly and return its proxy.
+    pub fn insert_bounds(&mut self, aabb: AABB) -> usize
+    {
+
+        let proxy = match self.free.pop()
+        {
+
+            Some(proxy) =>
+            {
+
+                self.bounds[proxy] = Some(aabb);
+                self.moved[proxy] = true;
+                proxy
+
+            },
+            None =>
+            {
+
+                self.bounds.push(Some(aabb));
+                self.moved.push(true);
+                self.bounds.len() - 1
+
+            }
+
+        };
+
+        let (min, max) = self.extent(&aabb);
+        self.endpoints.push(Endpoint { value: min, proxy, is_min: true });
+        self.endpoints.push(Endpoint { value: max, proxy, is_min: false });
+        self.inserted += 2;
+
+        return proxy;
+
+    }
+
+    /// Update the shape registered with the given proxy after it has moved or changed.
+    pub fn set(&mut self, proxy: usize, shape: &(impl Shape + ?Sized))
+    {
+
+        self.set_bounds(proxy, bounds(shape));
+
+    }
+
+    /// Update the bounding box registered with the given proxy.
+    pub fn set_bounds(&mut self, proxy: usize, aabb: AABB)
+    {
+
+        if let Some(Some(bounds)) = self.bounds.get_mut(proxy)
+        {
+
+            *bounds = aabb;
+            self.moved[proxy] = true;
+
+        }
+
+    }
+
+    /// Remove the shape registered with the given proxy. All of its pairs are reported as
+    /// removed immediately.
+    pub fn remove(&mut self, proxy: usize)
+    {
+
+        if let Some(bounds) = self.bounds.get_mut(proxy)
+        {
+
+            if bounds.take().is_none()
+            {
+
+                return;
+
+            }
+
+            self.endpoints.retain(|endpoint| endpoint.proxy != proxy);
+            self.free.push(proxy);
+
+            let events = &mut self.events;
+            self.pairs.retain(|pair, overlap|
+            {
+
+                let keep = pair.0 != proxy && pair.1 != proxy;
+                if !keep && *overlap
+                {
+
+                    events.push(PairEvent::Removed(pair.0, pair.1));
+
+                }
+
+                keep
+
+            });
+
+        }
+
+    }
+
+    /// The bounding box registered with the given proxy, if any.
+    pub fn get(&self, proxy: usize) -> Option<AABB>
+    {
+
+        return self.bounds.get(proxy).copied().flatten();
+
+    }
+
+    /// Returns true if the bounds of the two proxies overlapped at the last update.
+    pub fn contains_pair(&self, first: usize, second: usize) -> bool
+    {
+
+        return self.pairs.get(&ordered(first, second)) == Some(&true);
+
+    }
+
+    /// The pairs whose bounds overlapped at the last update, in no particular order.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_
+    {
+
+        return self.pairs.iter().filter(|(_pair, overlap)| **overlap).map(|(pair, _overlap)| *pair);
+
+    }
+
+    /// Take all pair events since the last call.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = PairEvent> + '_
+    {
+
+        return self.events.drain(..);
+
+    }
+
+    /// Re-sort the endpoints and update the set of overlapping pairs, recording an event
+    /// for every pair that started or stopped overlapping.
+    pub fn update(&mut self)
+    {
+
+        let along_x = match self.axis
+        {
+
+            SweepAxis::X => true,
+            SweepAxis::Y => false,
+            SweepAxis::Auto => self.spread_along_x()
+
+        };
+
+        //Switching axes reorders everything, as does adding many shapes at once, so those are
+        //the only times we sort from scratch
+        let resort = along_x != self.sorted_along_x || self.inserted * 2 > self.endpoints.len();
+        self.sorted_along_x = along_x;
+        self.inserted = 0;
+
+        for i in 0..self.endpoints.len()
+        {
+
+            let endpoint = self.endpoints[i];
+
+            if let Some(Some(aabb)) = self.bounds.get(endpoint.proxy)
+            {
+
+                let (min, max) = self.extent(aabb);
+                self.endpoints[i].value = if endpoint.is_min { min } else { max };
+
+            }
+
+        }
+
+        if resort
+        {
+
+            self.endpoints.sort_by(|a, b| a.value.total_cmp(&b.value).then(a.is_min.cmp(&b.is_min)));
+            self.sweep();
+
+        }
+        else
+        {
+
+            self.insertion_sort();
+
+        }
+
+        //Pairs that still overlap along the sweep axis may have moved apart across it, or
+        //together, without any endpoints swapping
+        for (pair, overlap) in self.pairs.iter_mut()
+        {
+
+            if self.moved[pair.0] || self.moved[pair.1]
+            {
+
+                if let (Some(Some(first)), Some(Some(second))) = (self.bounds.get(pair.0), self.bounds.get(pair.1))
+                {
+
+                    set_pair(&mut self.events, *pair, overlap, cross_overlap(first, second, along_x));
+
+                }
+
+            }
+
+        }
+
+        self.moved.iter_mut().for_each(|moved| *moved = false);
+
+    }
+
+    //Ties are broken so that a shape starting where another one ends is not considered
+    //overlapping, consistent with the epsilon comparisons elsewhere in the crate
+    fn insertion_sort(&mut self)
+    {
+
+        for i in 1..self.endpoints.len()
+        {
+
+            let mut j = i;
+
+            while j > 0 && out_of_order(&self.endpoints[j - 1], &self.endpoints[j])
+            {
+
+                let (left, right) = (self.endpoints[j - 1], self.endpoints[j]);
+                self.endpoints.swap(j - 1, j);
+                j -= 1;
+
+                //Only the lower end of one shape passing the upper end of another can change
+                //whether the two overlap
+                if left.is_min != right.is_min && left.proxy != right.proxy
+                {
+
+                    self.update_pair(ordered(left.proxy, right.proxy));
+
+                }
+
+            }
+
+        }
+
+    }
+
+    fn update_pair(&mut self, pair: (usize, usize))
+    {
+
+        let (Some(Some(first)), Some(Some(second))) = (self.bounds.get(pair.0), self.bounds.get(pair.1)) else
+        {
+
+            return;
+
+        };
+
+        //Overlapping along the sweep axis is overlapping across the other one
+        let along = cross_overlap(first, second, !self.sorted_along_x);
+        let across = cross_overlap(first, second, self.sorted_along_x);
+
+        if along
+        {
+
+            let overlap = self.pairs.entry(pair).or_insert(false);
+            set_pair(&mut self.events, pair, overlap, across);
+
+        }
+        else if self.pairs.remove(&pair) == Some(true)
+        {
+
+            self.events.push(PairEvent::Removed(pair.0, pair.1));
+
+        }
+
+    }
+
+    //Finds every pair overlapping along the sweep axis from scratch
+    fn sweep(&mut self)
+    {
+
+        let mut active: Vec<usize> = Vec::new();
+        let mut previous = core::mem::take(&mut self.pairs);
+
+        for endpoint in self.endpoints.iter()
+        {
+
+            if !endpoint.is_min
+            {
+
+                if let Some(index) = active.iter().position(|proxy| *proxy == endpoint.proxy)
+                {
+
+                    active.swap_remove(index);
+
+                }
+
+                continue;
+
+            }
+
+            for other in active.iter()
+            {
+
+                let pair = ordered(endpoint.proxy, *other);
+                let mut overlap = previous.remove(&pair).unwrap_or(false);
+
+                if let (Some(Some(first)), Some(Some(second))) = (self.bounds.get(pair.0), self.bounds.get(pair.1))
+                {
+
+                    set_pair(&mut self.events, pair, &mut overlap, cross_overlap(first, second, self.sorted_along_x));
+
+                }
+
+                self.pairs.insert(pair, overlap);
+
+            }
+
+            active.push(endpoint.proxy);
+
+        }
+
+        //Whatever is left no longer overlaps along the sweep axis
+        for (pair, overlap) in previous
+        {
+
+            if overlap
+            {
+
+                self.events.push(PairEvent::Removed(pair.0, pair.1));
+
+            }
+
+        }
+
+    }
+
+    fn extent(&self, aabb: &AABB) -> (f32, f32)
+    {
+
+        return if self.sorted_along_x { (aabb.position.0, aabb.position.0 + aabb.width) } else { (aabb.position.1, aabb.position.1 + aabb.height) };
+
+    }
+
+    //Whether the centers of the bounds vary more along X than along Y
+    fn spread_along_x(&self) -> bool
+    {
+
+        let mut count = 0.0;
+        let mut sum = (0.0, 0.0);
+        let mut sum_square = (0.0, 0.0);
+
+        for aabb in self.bounds.iter().flatten()
+        {
+
+            let center = (aabb.position.0 + aabb.width / 2.0, aabb.position.1 + aabb.height / 2.0);
+
+            count += 1.0;
+            sum = (sum.0 + center.0, sum.1 + center.1);
+            sum_square = (sum_square.0 + center.0 * center.0, sum_square.1 + center.1 * center.1);
+
+        }
+
+        if count < 1.0
+        {
+
+            return self.sorted_along_x;
+
+        }
+
+        let variance_x = sum_square.0 / count - (sum.0 / count) * (sum.0 / count);
+        let variance_y = sum_square.1 / count - (sum.1 / count) * (sum.1 / count);
+
+        return variance_x >= variance_y;
+
+    }
+
+}
+
+fn out_of_order(left: &Endpoint, right: &Endpoint) -> bool
+{
+
+    return left.value > right.value || (left.value == right.value && left.is_min && !right.is_min);
+
+}
+
+//Records whether a pair overlapping along the sweep axis also overlaps across it, with an
+//event if that changed
+fn set_pair(events: &mut Vec<PairEvent>, pair: (usize, usize), overlap: &mut bool, across: bool)
+{
+
+    if *overlap != across
+    {
+
+        *overlap = across;
+        events.push(if across { PairEvent::Added(pair.0, pair.1) } else { PairEvent::Removed(pair.0, pair.1) });
+
+    }
+
+}
+
+fn cross_overlap(left: &AABB, right: &AABB, along_x: bool) -> bool
+{
+
+    return if along_x
+    {
+
+        left.position.1 < right.position.1 + right.height && right.position.1 < left.position.1 + left.height
+
+    }
+    else
+    {
+
+        left.position.0 < right.position.0 + right.width && right.position.0 < left.position.0 + left.width
+
+    };
+
+}
+
+fn ordered(first: usize, second: usize) -> (usize, usize)
+{
+
+    return if first < second { (first, second) } else { (second, first) };
+
+}
+
+#[cfg(test)]
+mod broadphase_tests
+{
+
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::prelude::*;
+
+    fn random(seed: &mut u32) -> f32
+    {
+
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 17;
+        *seed ^= *seed << 5;
+
+        return (*seed % 10000) as f32 / 10000.0;
+
+    }
+
+    fn brute_force(shapes: &[AABB]) -> HashSet<(usize, usize)>
+    {
+
+        let mut pairs = HashSet::new();
+
+        for i in 0..shapes.len()
+        {
+
+            for j in (i + 1)..shapes.len()
+            {
+
+                if cross_overlap(&shapes[i], &shapes[j], true) && cross_overlap(&shapes[i], &shapes[j], false)
+                {
+
+                    pairs.insert((i, j));
+
+                }
+
+            }
+
+        }
+
+        return pairs;
+
+    }
+
+    #[test]
+    fn test_bounds()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (0.0, 2.0), 1.0);
+        let aabb = bounds(&capsule);
+
+        assert_eq!(aabb.position, (-1.0, -3.0));
+        assert_eq!((aabb.width, aabb.height), (2.0, 6.0));
+
+    }
+
+    #[test]
+    fn test_events()
+    {
+
+        let mut broadphase = SweepAndPrune::new(SweepAxis::X);
+
+        let a = broadphase.insert(&AABB::new((0.0, 0.0), 2.0, 2.0));
+        let b = broadphase.insert(&AABB::new((1.0, 1.0), 2.0, 2.0));
+        let c = broadphase.insert(&Circle::new((10.0, 0.0), 1.0));
+
+        broadphase.update();
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Added(a, b)]);
+        assert!(broadphase.contains_pair(b, a));
+
+        broadphase.set(c, &Circle::new((3.5, 3.5), 1.0));
+        broadphase.update();
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Added(b, c)]);
+
+        //Touching bounds do not overlap
+        broadphase.set_bounds(a, AABB::new((-1.0, 0.0), 2.0, 2.0));
+        broadphase.update();
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Removed(a, b)]);
+
+        broadphase.remove(b);
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Removed(b, c)]);
+        assert_eq!(broadphase.pairs().count(), 0);
+        assert!(broadphase.get(b).is_none());
+
+        //The proxy of the removed shape is reused
+        assert_eq!(broadphase.insert(&Circle::new((0.0, 0.0), 1.0)), b);
+
+    }
+
+    #[test]
+    fn test_moves_across_sweep_axis()
+    {
+
+        let mut broadphase = SweepAndPrune::new(SweepAxis::X);
+
+        let a = broadphase.insert_bounds(AABB::new((0.0, 0.0), 4.0, 2.0));
+        let b = broadphase.insert_bounds(AABB::new((1.0, 5.0), 2.0, 2.0));
+
+        broadphase.update();
+        assert!(broadphase.drain_events().next().is_none());
+
+        //Only the other axis changes, so no endpoints swap
+        broadphase.set_bounds(b, AABB::new((1.0, 1.0), 2.0, 2.0));
+        broadphase.update();
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Added(a, b)]);
+
+        broadphase.set_bounds(a, AABB::new((0.0, -5.0), 4.0, 2.0));
+        broadphase.update();
+
+        assert_eq!(broadphase.drain_events().collect::<Vec<_>>(), vec![PairEvent::Removed(a, b)]);
+        assert!(!broadphase.contains_pair(a, b));
+
+    }
+
+    #[test]
+    fn test_matches_brute_force()
+    {
+
+        for axis in [SweepAxis::X, SweepAxis::Y, SweepAxis::Auto]
+        {
+
+            let mut seed = 2023;
+            let mut broadphase = SweepAndPrune::new(axis);
+
+            let mut shapes: Vec<AABB> = (0..300).map(|_i| AABB::new((random(&mut seed) * 500.0, random(&mut seed) * 400.0), 4.0 + random(&mut seed) * 20.0, 4.0 + random(&mut seed) * 20.0)).collect();
+
+            for shape in shapes.iter()
+            {
+
+                broadphase.insert(shape);
+
+            }
+
+            let mut pairs = HashSet::new();
+
+            for _step in 0..20
+            {
+
+                broadphase.update();
+
+                for event in broadphase.drain_events()
+                {
+
+                    match event
+                    {
+
+                        PairEvent::Added(first, second) => assert!(pairs.insert((first, second))),
+                        PairEvent::Removed(first, second) => assert!(pairs.remove(&(first, second)))
+
+                    }
+
+                }
+
+                assert_eq!(pairs, brute_force(&shapes));
+                assert_eq!(broadphase.pairs().collect::<HashSet<_>>(), pairs);
+
+                //Scroll everything downwards, with a little sideways jitter
+                for (i, shape) in shapes.iter_mut().enumerate()
+                {
+
+                    shape.position.0 += random(&mut seed) * 2.0 - 1.0;
+                    shape.position.1 -= 1.0 + random(&mut seed) * 10.0;
+
+                    broadphase.set(i, shape);
+
+                }
+
+            }
+
+        }
+
+    }
+
+    //Run with `cargo test --release -- --ignored --nocapture bench_` to compare the broadphase
+    //with testing every pair
+    #[test]
+    #[ignore]
+    fn bench_sweep_and_prune()
+    {
+
+        let mut seed = 7;
+        let mut shapes: Vec<AABB> = (0..2000).map(|_i| AABB::new((random(&mut seed) * 512.0 - 256.0, random(&mut seed) * 3000.0), 8.0, 8.0)).collect();
+        let velocities: Vec<f32> = (0..shapes.len()).map(|_i| 0.5 + random(&mut seed) * 2.0).collect();
+
+        let mut broadphase = SweepAndPrune::new(SweepAxis::Y);
+        for shape in shapes.iter()
+        {
+
+            broadphase.insert(shape);
+
+        }
+
+        let steps = 100;
+        let mut brute = std::time::Duration::ZERO;
+        let mut swept = std::time::Duration::ZERO;
+
+        for _step in 0..steps
+        {
+
+            for (i, shape) in shapes.iter_mut().enumerate()
+            {
+
+                shape.position.1 -= velocities[i];
+                broadphase.set(i, shape);
+
+            }
+
+            let start = std::time::Instant::now();
+            let expected = brute_force(&shapes);
+            brute += start.elapsed();
+
+            let start = std::time::Instant::now();
+            broadphase.update();
+            broadphase.drain_events().for_each(drop);
+            swept += start.elapsed();
+
+            assert_eq!(broadphase.pairs().collect::<HashSet<_>>(), expected);
+
+        }
+
+        println!("2000 shapes scrolling along Y: pairwise {:?}, sweep and prune {:?} ({:.1}x)", brute / steps, swept / steps, brute.as_secs_f64() / swept.as_secs_f64());
+
+    }
+
+}
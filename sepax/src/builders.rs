@@ -0,0 +1,504 @@
+//! Functions which build common shapes out of polygons, so that hitboxes and bullet patterns
+//! can be prototyped without typing out vertices.
+//!
+//! Every polygon built here is convex and has counterclockwise winding when the Y-axis points up.
+//! Shapes which are not convex, such as stars and arcs, are returned as a set of convex pieces
+//! which share the given position, so the pieces can be moved and rotated together.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::builders::{regular_polygon, rectangle};
+//!
+//! let hexagon = regular_polygon((0.0, 0.0), 2.0, 6, 0.0);
+//! let beam = rectangle((3.0, 0.0), 4.0, 1.0, std::f32::consts::FRAC_PI_4);
+//!
+//! assert!(hexagon.is_convex());
+//! assert!(sat_overlap(&hexagon, &beam));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Rotate;
+use crate::polygon::Polygon;
+use crate::math;
+#[cfg(not(feature = "alloc"))]
+use crate::vertices::MAX_VERTICES;
+
+use core::f32::consts::{TAU, FRAC_PI_2};
+
+/// Builds a regular polygon centered on the given position, with its vertices on a circle of the
+/// given radius. The first vertex lies in the direction of the given rotation. Fewer than 3 sides
+/// are treated as 3. Without the `alloc` feature, more than `MAX_VERTICES` sides are treated as
+/// `MAX_VERTICES`.
+pub fn regular_polygon(position: (f32, f32), radius: f32, sides: usize, rotation: f32) -> Polygon
+{
+
+    let sides = sides.max(3);
+
+    #[cfg(not(feature = "alloc"))]
+    let sides = sides.min(MAX_VERTICES);
+
+    let step = TAU / sides as f32;
+
+    let mut polygon = Polygon::new(position);
+
+    for i in 0..sides
+    {
+
+        polygon.add(on_circle(radius, rotation + (step * i as f32)));
+
+    }
+
+    return polygon;
+
+}
+
+/// Builds a rectangle centered on the given position, rotated counterclockwise by the given angle.
+pub fn rectangle(position: (f32, f32), width: f32, height: f32, rotation: f32) -> Polygon
+{
+
+    let (x, y) = (width * 0.5, height * 0.5);
+
+    let mut polygon = Polygon::new(position);
+
+    polygon.add((-x, -y));
+    polygon.add((x, -y));
+    polygon.add((x, y));
+    polygon.add((-x, y));
+
+    polygon.rotate(rotation);
+
+    return polygon;
+
+}
+
+/// Builds a rectangle centered on the given position whose corners are rounded with the given radius,
+/// rotated counterclockwise by the given angle. Each corner is approximated by the given number of
+/// segments, with at least one. The radius is limited to half of the shorter side. Without the
+/// `alloc` feature, corners have at most 3 segments so the vertices fit in `MAX_VERTICES`.
+pub fn rounded_rectangle(position: (f32, f32), width: f32, height: f32, radius: f32, segments: usize, rotation: f32) -> Polygon
+{
+
+    let radius = f32::max(f32::min(radius, f32::min(width, height) * 0.5), 0.0);
+
+    if radius <= 0.0
+    {
+
+        return rectangle(position, width, height, rotation);
+
+    }
+
+    let segments = segments.max(1);
+
+    //Each corner takes one more vertex than it has segments
+    #[cfg(not(feature = "alloc"))]
+    let segments = segments.min((MAX_VERTICES / 4) - 1);
+
+    let step = FRAC_PI_2 / segments as f32;
+
+    let (x, y) = ((width * 0.5) - radius, (height * 0.5) - radius);
+    let corners = [(x, -y), (x, y), (-x, y), (-x, -y)];
+
+    let mut polygon = Polygon::new(position);
+
+    for (i, corner) in corners.iter().enumerate()
+    {
+
+        //Each corner starts where the previous one ended, pointing a quarter turn further
+        let start = (i as f32 * FRAC_PI_2) - FRAC_PI_2;
+
+        //The straight side before this corner is vertical for odd corners and horizontal otherwise
+        let side = if i % 2 == 1 { y } else { x };
+
+        for j in 0..=segments
+        {
+
+            //When the straight side has no length, its end points would repeat a vertex
+            if (j == 0 && i > 0 && side <= 0.0) || (j == segments && i == 3 && x <= 0.0)
+            {
+
+                continue;
+
+            }
+
+            let offset = on_circle(radius, start + (step * j as f32));
+            polygon.add((corner.0 + offset.0, corner.1 + offset.1));
+
+        }
+
+    }
+
+    polygon.rotate(rotation);
+
+    return polygon;
+
+}
+
+/// Builds a polygon approximating the circle with the given center and radius using the given number
+/// of segments, with at least 3. The vertices lie on the circle, starting in the direction of the X-axis.
+/// Without the `alloc` feature, the number of segments is limited to `MAX_VERTICES`.
+pub fn circle_polygon(position: (f32, f32), radius: f32, segments: usize) -> Polygon
+{
+
+    return regular_polygon(position, radius, segments, 0.0);
+
+}
+
+/// Builds a sector of the ring between the given radii, sweeping counterclockwise from the start angle
+/// to the end angle, as convex pieces. When the end angle is smaller than the start angle, the sweep
+/// wraps around through zero. An inner radius of zero gives a pie slice. The sector is split into the
+/// given number of pieces, or more when needed to keep each piece convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+/// use sepax2d::builders::arc;
+///
+/// let sweep = arc((0.0, 0.0), 3.0, 4.0, 0.0, std::f32::consts::PI, 8);
+///
+/// assert_eq!(sweep.len(), 8);
+/// assert!(sweep.iter().any(|piece| contains_point(piece, (0.0, 3.5))));
+/// assert!(!sweep.iter().any(|piece| contains_point(piece, (0.0, 2.0))));
+/// assert!(!sweep.iter().any(|piece| contains_point(piece, (0.0, -3.5))));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn arc(position: (f32, f32), inner_radius: f32, outer_radius: f32, start_angle: f32, end_angle: f32, segments: usize) -> Vec<Polygon>
+{
+
+    let start = start_angle;
+    let sweep = if end_angle < start_angle { math::rem_euclid(end_angle - start_angle, TAU) } else { f32::min(end_angle - start_angle, TAU) };
+
+    //A piece spanning half a turn or more would not be convex
+    let segments = segments.max(1).max((sweep / core::f32::consts::PI) as usize + 1);
+    let step = sweep / segments as f32;
+
+    let mut pieces = Vec::with_capacity(segments);
+
+    for i in 0..segments
+    {
+
+        let first = start + (step * i as f32);
+        let second = first + step;
+
+        let mut piece = Polygon::new(position);
+
+        piece.add(on_circle(outer_radius, first));
+        piece.add(on_circle(outer_radius, second));
+
+        if inner_radius > 0.0
+        {
+
+            piece.add(on_circle(inner_radius, second));
+            piece.add(on_circle(inner_radius, first));
+
+        }
+        else
+        {
+
+            piece.add((0.0, 0.0));
+
+        }
+
+        pieces.push(piece);
+
+    }
+
+    return pieces;
+
+}
+
+/// Builds the full ring between the given radii as convex pieces, split into the given number of
+/// pieces, with at least 3.
+#[cfg(feature = "alloc")]
+pub fn annulus(position: (f32, f32), inner_radius: f32, outer_radius: f32, segments: usize) -> Vec<Polygon>
+{
+
+    return arc(position, inner_radius, outer_radius, 0.0, TAU, segments.max(3));
+
+}
+
+/// Builds a star centered on the given position with the given number of points, with at least 2,
+/// as convex pieces: a regular polygon through the inner vertices and one triangle per point. The
+/// first point lies in the direction of the given rotation. The inner radius should be smaller
+/// than the outer radius.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+/// use sepax2d::builders::{rectangle, star};
+///
+/// let beam = rectangle((3.0, 0.0), 4.0, 1.0, std::f32::consts::FRAC_PI_4);
+/// let shuriken = star((10.0, 0.0), 2.0, 0.5, 4, 0.0);
+///
+/// assert!(shuriken.iter().all(|piece| piece.is_convex()));
+/// assert!(!shuriken.iter().any(|piece| sat_overlap(piece, &beam)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn star(position: (f32, f32), outer_radius: f32, inner_radius: f32, points: usize, rotation: f32) -> Vec<Polygon>
+{
+
+    let points = points.max(2);
+    let step = TAU / points as f32;
+    let half = step * 0.5;
+
+    let mut pieces = Vec::with_capacity(points + 1);
+
+    //With only two points the inner vertices form a line, so the triangles alone cover the star
+    if points > 2
+    {
+
+        pieces.push(regular_polygon(position, inner_radius, points, rotation + half));
+
+    }
+
+    for i in 0..points
+    {
+
+        let angle = rotation + (step * i as f32);
+
+        let mut tip = Polygon::new(position);
+
+        tip.add(on_circle(inner_radius, angle - half));
+        tip.add(on_circle(outer_radius, angle));
+        tip.add(on_circle(inner_radius, angle + half));
+
+        pieces.push(tip);
+
+    }
+
+    return pieces;
+
+}
+
+fn on_circle(radius: f32, angle: f32) -> (f32, f32)
+{
+
+    return (radius * math::cos(angle), radius * math::sin(angle));
+
+}
+
+#[cfg(test)]
+mod builders_tests
+{
+
+    use core::f32::consts::PI;
+
+    use super::*;
+    use crate::{float_equal, Shape, sat_overlap, contains_point};
+
+    fn assert_counterclockwise(polygon: &Polygon)
+    {
+
+        assert!(polygon.is_convex());
+        assert!(polygon.signed_area() > 0.0);
+
+    }
+
+    #[test]
+    fn test_regular_polygon()
+    {
+
+        let hexagon = regular_polygon((1.0, 2.0), 2.0, 6, FRAC_PI_2);
+
+        assert_eq!(hexagon.vertices.len(), 6);
+        assert_counterclockwise(&hexagon);
+        assert!(float_equal(hexagon.point(0).0, 1.0) && float_equal(hexagon.point(0).1, 4.0));
+        assert!((hexagon.signed_area() - (6.0 * f32::sqrt(3.0))).abs() < 0.0001);
+
+        for i in 0..6
+        {
+
+            let vertex = hexagon.vertices[i];
+            assert!(float_equal(f32::sqrt((vertex.0 * vertex.0) + (vertex.1 * vertex.1)), 2.0));
+
+        }
+
+        assert_eq!(regular_polygon((0.0, 0.0), 1.0, 1, 0.0).vertices.len(), 3);
+
+    }
+
+    #[test]
+    fn test_rectangle()
+    {
+
+        let flat = rectangle((0.0, 0.0), 4.0, 2.0, 0.0);
+        let turned = rectangle((0.0, 0.0), 4.0, 2.0, FRAC_PI_2);
+
+        assert_counterclockwise(&flat);
+        assert_counterclockwise(&turned);
+        assert!(float_equal(turned.signed_area(), 8.0));
+
+        assert!(contains_point(&flat, (1.9, 0.0)));
+        assert!(!contains_point(&flat, (0.0, 1.9)));
+        assert!(contains_point(&turned, (0.0, 1.9)));
+        assert!(!contains_point(&turned, (1.9, 0.0)));
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_rounded_rectangle()
+    {
+
+        let rounded = rounded_rectangle((0.0, 0.0), 6.0, 4.0, 1.0, 4, 0.0);
+
+        assert_eq!(rounded.vertices.len(), 20);
+        assert_counterclockwise(&rounded);
+
+        //The area approaches the rectangle minus the corners cut off by the circle
+        let exact = 24.0 - 4.0 + PI;
+        assert!(rounded.signed_area() < exact && rounded.signed_area() > exact - 0.1);
+
+        assert!(contains_point(&rounded, (2.9, 0.0)));
+        assert!(!contains_point(&rounded, (2.9, 1.9)));
+
+        //A radius of half the height gives a stadium without repeated vertices
+        let stadium = rounded_rectangle((0.0, 0.0), 6.0, 4.0, 5.0, 2, 0.0);
+
+        assert_eq!(stadium.vertices.len(), 10);
+        assert_counterclockwise(&stadium);
+
+        //Without rounding it is a plain rectangle
+        let square = rounded_rectangle((0.0, 0.0), 2.0, 2.0, 0.0, 3, 0.0);
+
+        assert_eq!(square.vertices.len(), 4);
+        assert_counterclockwise(&square);
+
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_vertex_limit()
+    {
+
+        let circle = circle_polygon((0.0, 0.0), 2.0, 32);
+
+        assert_eq!(circle.vertices.len(), crate::vertices::MAX_VERTICES);
+        assert_counterclockwise(&circle);
+
+        let rounded = rounded_rectangle((0.0, 0.0), 6.0, 4.0, 1.0, 4, 0.0);
+
+        assert_eq!(rounded.vertices.len(), 16);
+        assert_counterclockwise(&rounded);
+        assert!(contains_point(&rounded, (2.9, 0.0)));
+        assert!(!contains_point(&rounded, (2.9, 1.9)));
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_circle_polygon()
+    {
+
+        let circle = circle_polygon((3.0, 0.0), 2.0, 32);
+        let inside = crate::circle::Circle::new((3.0, 0.0), 1.9);
+
+        assert_eq!(circle.vertices.len(), 32);
+        assert_counterclockwise(&circle);
+        assert!(float_equal(circle.point(0).0, 5.0));
+        assert!(circle.signed_area() < 4.0 * PI && circle.signed_area() > 3.9 * PI);
+        assert!(sat_overlap(&circle, &inside));
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_arc()
+    {
+
+        let sweep = arc((1.0, 1.0), 2.0, 3.0, 0.0, FRAC_PI_2, 4);
+        let total: f32 = sweep.iter().map(Polygon::signed_area).sum();
+
+        assert_eq!(sweep.len(), 4);
+        assert!(total < (9.0 - 4.0) * PI / 4.0 && total > ((9.0 - 4.0) * PI / 4.0) - 0.2);
+
+        for piece in sweep.iter()
+        {
+
+            assert_eq!(piece.vertices.len(), 4);
+            assert_counterclockwise(piece);
+
+        }
+
+        assert!(sweep.iter().any(|piece| contains_point(piece, (1.0 + 1.75, 1.0 + 1.75))));
+        assert!(!sweep.iter().any(|piece| contains_point(piece, (2.0, 2.0))));
+
+        let slice = arc((0.0, 0.0), 0.0, 2.0, 0.0, PI, 1);
+
+        assert_eq!(slice.len(), 2);
+
+        for piece in slice.iter()
+        {
+
+            assert_eq!(piece.vertices.len(), 3);
+            assert_counterclockwise(piece);
+
+        }
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_arc_wraps_counterclockwise()
+    {
+
+        //From straight down to straight up through zero is the right half
+        let half = arc((0.0, 0.0), 3.0, 4.0, 3.0 * FRAC_PI_2, FRAC_PI_2, 8);
+        let total: f32 = half.iter().map(Polygon::signed_area).sum();
+
+        assert_eq!(half.len(), 8);
+        assert!(total < (16.0 - 9.0) * PI / 2.0 && total > ((16.0 - 9.0) * PI / 2.0) - 0.5);
+        assert!(half.iter().any(|piece| contains_point(piece, (3.5, 0.0))));
+        assert!(!half.iter().any(|piece| contains_point(piece, (-3.5, 0.0))));
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_annulus()
+    {
+
+        let ring = annulus((0.0, 0.0), 4.0, 5.0, 16);
+        let inside = crate::circle::Circle::new((0.0, 0.0), 3.0);
+        let crossing = crate::circle::Circle::new((0.0, 4.5), 0.25);
+
+        assert_eq!(ring.len(), 16);
+        assert!(ring.iter().all(|piece| piece.is_convex() && piece.signed_area() > 0.0));
+        assert!(!ring.iter().any(|piece| sat_overlap(piece, &inside)));
+        assert!(ring.iter().any(|piece| sat_overlap(piece, &crossing)));
+
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_star()
+    {
+
+        let star = star((0.0, 0.0), 3.0, 1.0, 5, FRAC_PI_2);
+
+        assert_eq!(star.len(), 6);
+
+        for piece in star.iter()
+        {
+
+            assert_counterclockwise(piece);
+
+        }
+
+        assert!(star.iter().any(|piece| contains_point(piece, (0.0, 2.9))));
+        assert!(star.iter().any(|piece| contains_point(piece, (0.0, 0.0))));
+        assert!(!star.iter().any(|piece| contains_point(piece, (0.0, -2.0))));
+
+        let bowtie = super::star((0.0, 0.0), 2.0, 0.5, 2, 0.0);
+
+        assert_eq!(bowtie.len(), 2);
+        assert!(bowtie.iter().all(|piece| piece.is_convex() && piece.signed_area() > 0.0));
+
+    }
+
+}
@@ -2,6 +2,7 @@
 use serde::{Serialize, ser::SerializeStruct, Serializer, Deserialize, Deserializer};
 
 use crate::circle::Circle;
+use crate::math;
 
 /// A struct representing a capsule, i.e. a rotated rectangle capped by half circles.
 /// The position is located in the center of the rectangle, with the arm vector denoting
@@ -16,7 +17,7 @@ use crate::circle::Circle;
 /// let capsule = Capsule::new((0.0, 0.0), (0.0, 1.0), 2.0);
 /// //A capsule formed from a rectangle with vertices (-1, 1), (1,1), (-1,-1), and (1,-1)
 /// 
-/// let hexagon = Polygon::from_vertices((1.5, 0.0), vec![(0.0, 0.0), (0.0, 1.0), (1.0, 2.0), (2.0, 1.0), (2.0, 0.0)]);
+/// let hexagon = Polygon::from_vertices((1.5, 0.0), [(0.0, 0.0), (0.0, 1.0), (1.0, 2.0), (2.0, 1.0), (2.0, 0.0)].into());
 /// let square = AABB::new((2.0, 3.0), 4.0, 0.5);
 /// 
 /// assert!(sat_overlap(&capsule, &hexagon));
@@ -47,7 +48,7 @@ impl Capsule
     fn set_perp(arm: (f32, f32), radius: f32) -> (f32, f32)
     {
 
-        let length = f32::sqrt((arm.0 * arm.0) + (arm.1 * arm.1));
+        let length = math::sqrt((arm.0 * arm.0) + (arm.1 * arm.1));
         let mut perp = (-arm.1, arm.0);
 
         if length > f32::EPSILON
@@ -107,24 +108,6 @@ impl Capsule
 
     }
 
-    fn points(&self) -> [(f32, f32); 6]
-    {
-
-        //TODO: Determine if this needs to be optimized or if the compiler does it for us
-        return 
-        [
-            
-            self.arm,
-            (-self.arm.0, -self.arm.1),
-            (self.arm.0 + self.perp.0, self.arm.1 + self.perp.1),
-            (self.arm.0 - self.perp.0, self.arm.1 - self.perp.1),
-            (-self.arm.0 + self.perp.0, -self.arm.1 + self.perp.1),
-            (-self.arm.0 - self.perp.0, -self.arm.1 - self.perp.1)
-
-        ];
-
-    }
-    
 }
 
 impl crate::Shape for Capsule
@@ -199,7 +182,12 @@ impl crate::Shape for Capsule
     fn get_closest(&self, target: (f32, f32)) -> (f32, f32)
     {
 
-        return crate::closest(self.position, target, &self.points());
+        //The closest point of the core segment, as the rounded sides are all the same distance
+        //from it. The corners of the rectangle between the end caps give the wrong axis.
+        let start = (self.position.0 - self.arm.0, self.position.1 - self.arm.1);
+        let end = (self.position.0 + self.arm.0, self.position.1 + self.arm.1);
+
+        return crate::closest_on_segment(start, end, target);
 
     }
 
@@ -226,8 +214,8 @@ impl crate::Rotate for Capsule
     fn rotate(&mut self, angle: f32)
     {
 
-        let sin = f32::sin(angle);
-        let cos = f32::cos(angle);
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
 
         self.rotate_sincos(sin, cos);
 
@@ -243,6 +231,27 @@ impl crate::Rotate for Capsule
 
 }
 
+impl crate::Distance for Capsule
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        let start = (self.position.0 - self.arm.0, self.position.1 - self.arm.1);
+        let end = (self.position.0 + self.arm.0, self.position.1 + self.arm.1);
+
+        let center = crate::closest_on_segment(start, end, point);
+
+        //On the central segment itself, the nearest side is straight out along the perpendicular
+        let length = math::sqrt((self.perp.0 * self.perp.0) + (self.perp.1 * self.perp.1));
+        let fallback = if length > f32::EPSILON { (self.perp.0 / length, self.perp.1 / length) } else { (1.0, 0.0) };
+
+        return crate::round_boundary(center, self.radius, point, fallback);
+
+    }
+
+}
+
 #[cfg(feature = "serde")]
 #[derive(Serialize, Deserialize)]
 #[serde(rename = "Capsule")]
@@ -291,7 +300,7 @@ mod capsule_tests
 {
 
     use super::*;
-    use crate::{float_equal, Shape};
+    use crate::{float_equal, Shape, Distance};
 
     #[test]
     fn test_num_axes()
@@ -357,6 +366,32 @@ mod capsule_tests
 
     }
 
+    #[test]
+    fn test_get_closest()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (0.0, 2.0), 1.0);
+
+        assert_eq!(capsule.get_closest((4.0, 1.0)), (0.0, 1.0));
+        assert_eq!(capsule.get_closest((1.0, 5.0)), (0.0, 2.0));
+        assert_eq!(capsule.get_closest((-1.0, -3.0)), (0.0, -2.0));
+
+    }
+
+    #[test]
+    fn test_capsules_apart()
+    {
+
+        //The end caps come close, but the closest corner of the other capsule gave an axis
+        //which could not separate them
+        let left = Capsule::new((5.605054, -3.531732), (3.399858, 2.0267756), 0.7388402);
+        let right = Capsule::new((7.281885, 2.8973072), (1.9625918, -2.7437966), 0.80575633);
+
+        assert!(!crate::sat_overlap(&left, &right));
+        assert!(!crate::sat_overlap(&right, &left));
+
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde()
@@ -373,4 +408,32 @@ mod capsule_tests
 
     }
 
+    #[test]
+    fn test_boundary()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (2.0, 0.0), 1.0);
+
+        let (point, normal, distance) = capsule.boundary((1.0, 0.5));
+
+        assert!(float_equal(point.0, 1.0));
+        assert!(float_equal(point.1, 1.0));
+        assert!(float_equal(normal.0, 0.0));
+        assert!(float_equal(normal.1, 1.0));
+        assert!(float_equal(distance, -0.5));
+
+        let (point, _, distance) = capsule.boundary((5.0, 0.0));
+
+        assert!(float_equal(point.0, 3.0));
+        assert!(float_equal(point.1, 0.0));
+        assert!(float_equal(distance, 2.0));
+
+        let (_, normal, distance) = capsule.boundary((-1.0, 0.0));
+
+        assert!(float_equal(normal.0.abs(), 0.0));
+        assert!(float_equal(normal.1.abs(), 1.0));
+        assert!(float_equal(distance, -1.0));
+
+    }
+
 }
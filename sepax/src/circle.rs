@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
+use crate::math;
+
 /// A struct representing a circle via a position and radius.
 /// 
 /// # Examples
@@ -16,7 +18,7 @@ use serde::{Serialize, Deserialize};
 /// assert!(resolution.0 - difference < f32::EPSILON && resolution.0 - difference > -f32::EPSILON);
 /// assert!(resolution.1 - difference < f32::EPSILON && resolution.1 - difference > -f32::EPSILON);
 /// 
-/// let polygon = Polygon::from_vertices((0.0, 3.0), vec![(0.0, 0.0), (1.0, 0.0), (1.0, -1.5), (0.0, -1.5)]);
+/// let polygon = Polygon::from_vertices((0.0, 3.0), [(0.0, 0.0), (1.0, 0.0), (1.0, -1.5), (0.0, -1.5)].into());
 /// assert!(sat_overlap(&polygon, &circle1));
 /// assert!(sat_overlap(&circle1, &polygon));
 /// ```
@@ -86,7 +88,7 @@ impl crate::Shape for Circle
         if !normalize
         {
 
-            magnitude = f32::sqrt((axis.0 * axis.0) + (axis.1 * axis.1));
+            magnitude = math::sqrt((axis.0 * axis.0) + (axis.1 * axis.1));
 
         }
 
@@ -132,12 +134,24 @@ impl crate::Rotate for Circle
 
 }
 
+impl crate::Distance for Circle
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return crate::round_boundary(self.position, self.radius, point, (1.0, 0.0));
+
+    }
+
+}
+
 #[cfg(test)]
 mod circle_tests
 {
 
     use super::*;
-    use crate::{float_equal, Shape};
+    use crate::{float_equal, Shape, Distance};
 
     #[test]
     fn test_num_axes()
@@ -194,4 +208,24 @@ mod circle_tests
 
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_boundary()
+    {
+
+        let circle = Circle::new((1.0, 2.0), 2.0);
+
+        let (point, normal, distance) = circle.boundary((1.0, 5.0));
+
+        assert!(float_equal(point.0, 1.0));
+        assert!(float_equal(point.1, 4.0));
+        assert!(float_equal(normal.0, 0.0));
+        assert!(float_equal(normal.1, 1.0));
+        assert!(float_equal(distance, 1.0));
+
+        let (_, _, distance) = circle.boundary((1.0, 2.0));
+
+        assert!(float_equal(distance, -2.0));
+
+    }
+
+}
@@ -0,0 +1,431 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use crate::{Shape, Distance};
+use crate::polygon::Polygon;
+use crate::circle::Circle;
+use crate::aabb::AABB;
+use crate::capsule::Capsule;
+use crate::parallelogram::Parallelogram;
+use crate::point::Point;
+use crate::segment::Segment;
+
+/// A single collider of any of the supported shape types. Use this when the type of
+/// shape is only known at runtime, for example when hitboxes are loaded from a file.
+///
+/// With the `serde` feature enabled, colliders use an externally tagged format, where
+/// the name of the shape type wraps its fields. A hitbox file is simply a list of colliders.
+///
+/// In RON:
+///
+/// ```text
+/// [
+///     AABB((position: (-16.0, -16.0), width: 32.0, height: 32.0)),
+///     Circle((position: (0.0, 8.0), radius: 4.0)),
+///     Capsule((position: (0.0, 0.0), arm: (0.0, 12.0), radius: 3.0)),
+///     Parallelogram((position: (0.0, 0.0), u: (8.0, 0.0), v: (2.0, 4.0))),
+///     Polygon((position: (0.0, 0.0), vertices: [(0.0, 4.0), (-4.0, -4.0), (4.0, -4.0)])),
+///     Point((position: (0.0, 0.0))),
+///     Segment((position: (0.0, 0.0), vector: (0.0, 64.0))),
+/// ]
+/// ```
+///
+/// In JSON:
+///
+/// ```text
+/// [
+///     { "AABB": { "position": [-16.0, -16.0], "width": 32.0, "height": 32.0 } },
+///     { "Circle": { "position": [0.0, 8.0], "radius": 4.0 } },
+///     { "Capsule": { "position": [0.0, 0.0], "arm": [0.0, 12.0], "radius": 3.0 } },
+///     { "Parallelogram": { "position": [0.0, 0.0], "u": [8.0, 0.0], "v": [2.0, 4.0] } },
+///     { "Polygon": { "position": [0.0, 0.0], "vertices": [[0.0, 4.0], [-4.0, -4.0], [4.0, -4.0]] } },
+///     { "Point": { "position": [0.0, 0.0] } },
+///     { "Segment": { "position": [0.0, 0.0], "vector": [0.0, 64.0] } }
+/// ]
+/// ```
+///
+/// Every field has the same meaning as in the constructor of the corresponding shape.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let colliders = vec![Collider::Circle(Circle::new((0.0, 0.0), 2.0)), Collider::AABB(AABB::new((3.0, 0.0), 1.0, 1.0))];
+/// let square = AABB::new((1.0, -1.0), 2.0, 2.0);
+///
+/// assert!(colliders.iter().all(|collider| sat_overlap(collider, &square)));
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Collider
+{
+
+    Polygon(Polygon),
+    Circle(Circle),
+    AABB(AABB),
+    Capsule(Capsule),
+    Parallelogram(Parallelogram),
+    Point(Point),
+    Segment(Segment)
+
+}
+
+impl Collider
+{
+
+    /// Access the underlying shape as a trait object.
+    pub fn shape(&self) -> &dyn Shape
+    {
+
+        return match self
+        {
+
+            Collider::Polygon(polygon) => polygon,
+            Collider::Circle(circle) => circle,
+            Collider::AABB(aabb) => aabb,
+            Collider::Capsule(capsule) => capsule,
+            Collider::Parallelogram(gram) => gram,
+            Collider::Point(point) => point,
+            Collider::Segment(segment) => segment
+
+        };
+
+    }
+
+    /// Mutably access the underlying shape as a trait object.
+    pub fn shape_mut(&mut self) -> &mut dyn Shape
+    {
+
+        return match self
+        {
+
+            Collider::Polygon(polygon) => polygon,
+            Collider::Circle(circle) => circle,
+            Collider::AABB(aabb) => aabb,
+            Collider::Capsule(capsule) => capsule,
+            Collider::Parallelogram(gram) => gram,
+            Collider::Point(point) => point,
+            Collider::Segment(segment) => segment
+
+        };
+
+    }
+
+}
+
+impl Shape for Collider
+{
+
+    fn position(&self) -> (f32, f32)
+    {
+
+        return self.shape().position();
+
+    }
+
+    fn set_position(&mut self, position: (f32, f32))
+    {
+
+        self.shape_mut().set_position(position);
+
+    }
+
+    fn num_axes(&self) -> usize
+    {
+
+        return self.shape().num_axes();
+
+    }
+
+    fn get_axis(&self, index: usize, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return self.shape().get_axis(index, target);
+
+    }
+
+    fn project(&self, axis: (f32, f32), normalize: bool) -> (f32, f32)
+    {
+
+        return self.shape().project(axis, normalize);
+
+    }
+
+    fn needs_closest(&self, index: usize) -> bool
+    {
+
+        return self.shape().needs_closest(index);
+
+    }
+
+    fn get_closest(&self, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return self.shape().get_closest(target);
+
+    }
+
+    fn point(&self, index: usize) -> (f32, f32)
+    {
+
+        return self.shape().point(index);
+
+    }
+
+}
+
+impl Distance for Collider
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return match self
+        {
+
+            Collider::Polygon(polygon) => polygon.boundary(point),
+            Collider::Circle(circle) => circle.boundary(point),
+            Collider::AABB(aabb) => aabb.boundary(point),
+            Collider::Capsule(capsule) => capsule.boundary(point),
+            Collider::Parallelogram(gram) => gram.boundary(point),
+            Collider::Point(shape) => shape.boundary(point),
+            Collider::Segment(segment) => segment.boundary(point)
+
+        };
+
+    }
+
+}
+
+impl From<Polygon> for Collider
+{
+
+    fn from(polygon: Polygon) -> Self
+    {
+
+        return Collider::Polygon(polygon);
+
+    }
+
+}
+
+impl From<Circle> for Collider
+{
+
+    fn from(circle: Circle) -> Self
+    {
+
+        return Collider::Circle(circle);
+
+    }
+
+}
+
+impl From<AABB> for Collider
+{
+
+    fn from(aabb: AABB) -> Self
+    {
+
+        return Collider::AABB(aabb);
+
+    }
+
+}
+
+impl From<Capsule> for Collider
+{
+
+    fn from(capsule: Capsule) -> Self
+    {
+
+        return Collider::Capsule(capsule);
+
+    }
+
+}
+
+impl From<Parallelogram> for Collider
+{
+
+    fn from(gram: Parallelogram) -> Self
+    {
+
+        return Collider::Parallelogram(gram);
+
+    }
+
+}
+
+impl From<Point> for Collider
+{
+
+    fn from(point: Point) -> Self
+    {
+
+        return Collider::Point(point);
+
+    }
+
+}
+
+impl From<Segment> for Collider
+{
+
+    fn from(segment: Segment) -> Self
+    {
+
+        return Collider::Segment(segment);
+
+    }
+
+}
+
+#[cfg(test)]
+mod collider_tests
+{
+
+    use super::*;
+    use crate::{float_equal, sat_overlap, sat_collision};
+
+    #[test]
+    fn test_shape_dispatch()
+    {
+
+        let circle = Circle::new((2.0, -0.5), 1.0);
+        let square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)].into());
+
+        let collider = Collider::from(circle);
+
+        assert_eq!(collider.num_axes(), 1);
+        assert!(sat_overlap(&collider, &square));
+
+        let resolution = sat_collision(&collider, &square);
+        assert!(float_equal(resolution.0, 0.0));
+        assert!(float_equal(resolution.1, 0.5));
+
+    }
+
+    #[test]
+    fn test_set_position()
+    {
+
+        let mut collider = Collider::from(AABB::new((0.0, 0.0), 2.0, 2.0));
+        collider.set_position((3.0, 4.0));
+
+        match collider
+        {
+
+            Collider::AABB(aabb) => assert_eq!(aabb.position, (3.0, 4.0)),
+            _ => panic!("Expected an AABB collider")
+
+        }
+
+    }
+
+    #[cfg(feature = "serde")]
+    fn all_shapes() -> Vec<Collider>
+    {
+
+        return vec!
+        [
+
+            Collider::from(Polygon::from_vertices((1.0, -2.0), [(0.0, 4.0), (-4.0, -4.0), (4.0, -4.0)].into())),
+            Collider::from(Circle::new((0.5, 8.0), 4.25)),
+            Collider::from(AABB::new((-16.0, -16.0), 32.0, 24.0)),
+            Collider::from(Capsule::new((0.0, 3.0), (0.0, 12.0), 3.0)),
+            Collider::from(Parallelogram::new((2.0, 0.0), (8.0, 0.0), (2.0, 4.0))),
+            Collider::from(Point::new((-1.5, 2.0))),
+            Collider::from(Segment::new((0.0, 0.0), (0.0, 64.0)))
+
+        ];
+
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ron_round_trip()
+    {
+
+        let colliders = all_shapes();
+
+        let serialized = ron::to_string(&colliders).unwrap();
+        let deserialized: Vec<Collider> = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.len(), colliders.len());
+        assert_eq!(ron::to_string(&deserialized).unwrap(), serialized);
+
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip()
+    {
+
+        let colliders = all_shapes();
+
+        let serialized = serde_json::to_string(&colliders).unwrap();
+        let deserialized: Vec<Collider> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.len(), colliders.len());
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ron_format()
+    {
+
+        let source = "[
+            AABB((position: (-16.0, -16.0), width: 32.0, height: 32.0)),
+            Circle((position: (0.0, 8.0), radius: 4.0)),
+            Capsule((position: (0.0, 0.0), arm: (0.0, 12.0), radius: 3.0)),
+            Parallelogram((position: (0.0, 0.0), u: (8.0, 0.0), v: (2.0, 4.0))),
+            Polygon((position: (0.0, 0.0), vertices: [(0.0, 4.0), (-4.0, -4.0), (4.0, -4.0)])),
+            Point((position: (0.0, 0.0))),
+            Segment((position: (0.0, 0.0), vector: (0.0, 64.0))),
+        ]";
+
+        let colliders: Vec<Collider> = ron::from_str(source).unwrap();
+
+        assert_eq!(colliders.len(), 7);
+        assert!(matches!(colliders[0], Collider::AABB(aabb) if float_equal(aabb.width, 32.0)));
+        assert!(matches!(colliders[1], Collider::Circle(circle) if float_equal(circle.radius, 4.0)));
+        assert!(matches!(colliders[2], Collider::Capsule(capsule) if float_equal(capsule.perp().0, -3.0)));
+        assert!(matches!(colliders[3], Collider::Parallelogram(gram) if float_equal(gram.v.1, 4.0)));
+        assert!(matches!(&colliders[4], Collider::Polygon(polygon) if polygon.vertices.len() == 3));
+        assert!(matches!(colliders[5], Collider::Point(point) if point.position == (0.0, 0.0)));
+        assert!(matches!(colliders[6], Collider::Segment(segment) if float_equal(segment.vector.1, 64.0)));
+
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_format()
+    {
+
+        let source = r#"[
+            { "AABB": { "position": [-16.0, -16.0], "width": 32.0, "height": 32.0 } },
+            { "Circle": { "position": [0.0, 8.0], "radius": 4.0 } },
+            { "Capsule": { "position": [0.0, 0.0], "arm": [0.0, 12.0], "radius": 3.0 } },
+            { "Parallelogram": { "position": [0.0, 0.0], "u": [8.0, 0.0], "v": [2.0, 4.0] } },
+            { "Polygon": { "position": [0.0, 0.0], "vertices": [[0.0, 4.0], [-4.0, -4.0], [4.0, -4.0]] } },
+            { "Point": { "position": [0.0, 0.0] } },
+            { "Segment": { "position": [0.0, 0.0], "vector": [0.0, 64.0] } }
+        ]"#;
+
+        let colliders: Vec<Collider> = serde_json::from_str(source).unwrap();
+
+        assert_eq!(colliders.len(), 7);
+        assert!(matches!(colliders[0], Collider::AABB(aabb) if float_equal(aabb.height, 32.0)));
+        assert!(matches!(colliders[1], Collider::Circle(circle) if float_equal(circle.position.1, 8.0)));
+        assert!(matches!(colliders[2], Collider::Capsule(capsule) if float_equal(capsule.perp().0, -3.0)));
+        assert!(matches!(colliders[3], Collider::Parallelogram(gram) if float_equal(gram.u.0, 8.0)));
+        assert!(matches!(&colliders[4], Collider::Polygon(polygon) if float_equal(polygon.vertices[1].0, -4.0)));
+        assert!(matches!(colliders[6], Collider::Segment(segment) if segment.end() == (0.0, 64.0)));
+
+    }
+
+}
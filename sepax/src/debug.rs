@@ -0,0 +1,456 @@
+//! Renders shapes and the steps of the SAT calculation into a standalone SVG file,
+//! so that collision results can be inspected visually.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use sepax2d::prelude::*;
+//! use sepax2d::debug::SvgDebug;
+//!
+//! let square = AABB::new((0.0, 0.0), 4.0, 4.0);
+//! let circle = Circle::new((4.5, 2.0), 1.0);
+//!
+//! let mut svg = SvgDebug::new();
+//! svg.collision(&square, &circle);
+//! svg.save("collision.svg").unwrap();
+//! ```
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::Shape;
+
+const LEFT_COLOR: &str = "#1f77b4";
+const RIGHT_COLOR: &str = "#d62728";
+const AXIS_COLOR: &str = "#999999";
+const NORMAL_COLOR: &str = "#2ca02c";
+const RESOLUTION_COLOR: &str = "#ff7f0e";
+
+/// The number of evenly spaced directions used to trace the outline of curved shapes.
+const OUTLINE_SEGMENTS: usize = 64;
+
+/// Collects shapes, axes and vectors and writes them into an SVG document. The Y-axis
+/// points up, matching the convention used for rotations.
+#[derive(Clone, Debug)]
+pub struct SvgDebug
+{
+
+    elements: Vec<String>,
+    points: Vec<((f32, f32), String)>,
+    min: (f32, f32),
+    max: (f32, f32),
+    empty: bool
+
+}
+
+impl SvgDebug
+{
+
+    /// Create an empty drawing.
+    pub fn new() -> SvgDebug
+    {
+
+        return SvgDebug { elements: Vec::new(), points: Vec::new(), min: (f32::MAX, f32::MAX), max: (f32::MIN, f32::MIN), empty: true };
+
+    }
+
+    fn include(&mut self, point: (f32, f32))
+    {
+
+        self.min = (f32::min(self.min.0, point.0), f32::min(self.min.1, point.1));
+        self.max = (f32::max(self.max.0, point.0), f32::max(self.max.1, point.1));
+        self.empty = false;
+
+    }
+
+    /// Draw the outline of the given shape with the given stroke color.
+    pub fn shape(&mut self, shape: &(impl Shape + ?Sized), color: &str)
+    {
+
+        let outline = outline(shape);
+
+        let mut points = String::new();
+        for point in outline.iter()
+        {
+
+            self.include(*point);
+            let _ = write!(points, "{},{} ", point.0, point.1);
+
+        }
+
+        self.elements.push(format!("<polygon points=\"{}\" fill=\"{}\" fill-opacity=\"0.15\" stroke=\"{}\" vector-effect=\"non-scaling-stroke\"/>", points.trim_end(), color, color));
+
+        let position = shape.position();
+        self.point(position, color);
+
+    }
+
+    /// Mark a single point with a small dot.
+    pub fn point(&mut self, point: (f32, f32), color: &str)
+    {
+
+        self.include(point);
+        self.points.push((point, color.to_string()));
+
+    }
+
+    /// Draw a line segment between the two given points.
+    pub fn segment(&mut self, start: (f32, f32), end: (f32, f32), color: &str, width: f32)
+    {
+
+        self.include(start);
+        self.include(end);
+        self.elements.push(format!("<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\" vector-effect=\"non-scaling-stroke\"/>", start.0, start.1, end.0, end.1, color, width));
+
+    }
+
+    /// Draw an arrow for the given vector, starting at the given origin.
+    pub fn vector(&mut self, origin: (f32, f32), vector: (f32, f32), color: &str)
+    {
+
+        let end = (origin.0 + vector.0, origin.1 + vector.1);
+        self.segment(origin, end, color, 2.0);
+
+        let length = f32::sqrt((vector.0 * vector.0) + (vector.1 * vector.1));
+        if length > f32::EPSILON
+        {
+
+            //Size the head relative to the arrow so that short vectors are still readable
+            let head = f32::min(length * 0.3, 0.5);
+            let direction = (vector.0 / length, vector.1 / length);
+            let perp = (-direction.1, direction.0);
+            let base = (end.0 - direction.0 * head, end.1 - direction.1 * head);
+
+            let left = (base.0 + perp.0 * head * 0.5, base.1 + perp.1 * head * 0.5);
+            let right = (base.0 - perp.0 * head * 0.5, base.1 - perp.1 * head * 0.5);
+
+            self.elements.push(format!("<polygon points=\"{},{} {},{} {},{}\" fill=\"{}\"/>", end.0, end.1, left.0, left.1, right.0, right.1, color));
+
+        }
+
+    }
+
+    /// Draw the axes used by the SAT calculation between the two shapes, each one with
+    /// the projections of both shapes onto it.
+    pub fn axes(&mut self, left: &(impl Shape + ?Sized), right: &(impl Shape + ?Sized))
+    {
+
+        let center = midpoint(left.position(), right.position());
+
+        for axis in sat_axes(left, right).chain(sat_axes(right, left))
+        {
+
+            let (min_l, max_l) = left.project(axis, true);
+            let (min_r, max_r) = right.project(axis, true);
+
+            let min = f32::min(min_l, min_r) - 1.0;
+            let max = f32::max(max_l, max_r) + 1.0;
+
+            self.segment(along(center, axis, min, 0.0), along(center, axis, max, 0.0), AXIS_COLOR, 0.5);
+
+            //Offset the two projections to either side of the axis so that they stay visible where they overlap
+            self.segment(along(center, axis, min_l, 0.15), along(center, axis, max_l, 0.15), LEFT_COLOR, 3.0);
+            self.segment(along(center, axis, min_r, -0.15), along(center, axis, max_r, -0.15), RIGHT_COLOR, 3.0);
+
+        }
+
+    }
+
+    /// Draw both shapes, their SAT axes and projections, and, if they collide, the contact
+    /// normal and the resolution vector returned by `sat_collision`. The left shape is drawn
+    /// in blue and the right shape in red.
+    pub fn collision(&mut self, left: &(impl Shape + ?Sized), right: &(impl Shape + ?Sized))
+    {
+
+        self.shape(left, LEFT_COLOR);
+        self.shape(right, RIGHT_COLOR);
+        self.axes(left, right);
+
+        let resolution = crate::sat_collision(left, right);
+        let length = f32::sqrt((resolution.0 * resolution.0) + (resolution.1 * resolution.1));
+
+        if length > f32::EPSILON
+        {
+
+            let normal = (resolution.0 / length, resolution.1 / length);
+            let center = midpoint(left.position(), right.position());
+
+            self.vector(center, normal, NORMAL_COLOR);
+            self.vector(right.position(), resolution, RESOLUTION_COLOR);
+
+        }
+
+    }
+
+    /// Render the drawing into an SVG document.
+    pub fn to_svg(&self) -> String
+    {
+
+        let (min, max) = if self.empty { ((-1.0, -1.0), (1.0, 1.0)) } else { (self.min, self.max) };
+
+        let padding = f32::max(max.0 - min.0, max.1 - min.1) * 0.1 + 1.0;
+        let x = min.0 - padding;
+        let y = min.1 - padding;
+        let width = max.0 - min.0 + 2.0 * padding;
+        let height = max.1 - min.1 + 2.0 * padding;
+
+        let mut svg = String::new();
+
+        //Flip the Y-axis, so that it points up as in the rest of the crate
+        let _ = writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"800\" height=\"{}\">", x, -y - height, width, height, 800.0 * height / width);
+        let _ = writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"white\"/>", x, -y - height, width, height);
+        let _ = writeln!(svg, "<g transform=\"scale(1,-1)\">");
+
+        for element in self.elements.iter()
+        {
+
+            let _ = writeln!(svg, "{}", element);
+
+        }
+
+        //Points are sized relative to the whole drawing, which is only known at this point
+        let radius = f32::max(width, height) * 0.005;
+        for (point, color) in self.points.iter()
+        {
+
+            let _ = writeln!(svg, "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>", point.0, point.1, radius, color);
+
+        }
+
+        let _ = writeln!(svg, "</g>");
+        let _ = writeln!(svg, "</svg>");
+
+        return svg;
+
+    }
+
+    /// Write the drawing to an SVG file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()>
+    {
+
+        return std::fs::write(path, self.to_svg());
+
+    }
+
+}
+
+impl Default for SvgDebug
+{
+
+    fn default() -> Self
+    {
+
+        return SvgDebug::new();
+
+    }
+
+}
+
+/// Write an SVG file showing the SAT calculation between the two given shapes. Useful to call
+/// from a failing test before asserting.
+pub fn save_collision(path: impl AsRef<Path>, left: &(impl Shape + ?Sized), right: &(impl Shape + ?Sized)) -> std::io::Result<()>
+{
+
+    let mut svg = SvgDebug::new();
+    svg.collision(left, right);
+
+    return svg.save(path);
+
+}
+
+/// Traces the outline of any convex shape using only its projections: the shape is the
+/// intersection of its supporting half-planes, so neighbouring support lines meet at points
+/// on its boundary. The shape's own axes are included so that polygon corners are exact.
+pub fn outline(shape: &(impl Shape + ?Sized)) -> Vec<(f32, f32)>
+{
+
+    let mut angles = Vec::with_capacity(OUTLINE_SEGMENTS + 2 * shape.num_axes());
+
+    for i in 0..OUTLINE_SEGMENTS
+    {
+
+        angles.push((i as f32 / OUTLINE_SEGMENTS as f32) * std::f32::consts::TAU);
+
+    }
+
+    for i in 0..shape.num_axes()
+    {
+
+        if !shape.needs_closest(i)
+        {
+
+            let axis = shape.get_axis(i, shape.position());
+
+            if (axis.0 * axis.0) + (axis.1 * axis.1) > f32::EPSILON
+            {
+
+                let angle = f32::atan2(axis.1, axis.0);
+                angles.push(angle.rem_euclid(std::f32::consts::TAU));
+                angles.push((angle + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU));
+
+            }
+
+        }
+
+    }
+
+    angles.sort_by(f32::total_cmp);
+    angles.dedup_by(|a, b| (*a - *b).abs() < 0.0001);
+
+    let supports: Vec<((f32, f32), f32)> = angles.iter().map(|angle|
+    {
+
+        let direction = (f32::cos(*angle), f32::sin(*angle));
+        (direction, shape.project(direction, true).1)
+
+    }).collect();
+
+    let mut outline = Vec::with_capacity(supports.len());
+
+    for i in 0..supports.len()
+    {
+
+        let (d1, h1) = supports[i];
+        let (d2, h2) = supports[(i + 1) % supports.len()];
+
+        let determinant = (d1.0 * d2.1) - (d1.1 * d2.0);
+
+        if determinant.abs() > f32::EPSILON
+        {
+
+            outline.push((((h1 * d2.1) - (h2 * d1.1)) / determinant, ((d1.0 * h2) - (d2.0 * h1)) / determinant));
+
+        }
+
+    }
+
+    return outline;
+
+}
+
+//The normalized axes the SAT calculation tests when axes_shape provides the axes
+fn sat_axes<'a>(axes_shape: &'a (impl Shape + ?Sized), projected: &'a (impl Shape + ?Sized)) -> impl Iterator<Item = (f32, f32)> + 'a
+{
+
+    return (0..axes_shape.num_axes()).filter_map(move |i|
+    {
+
+        let closest = if axes_shape.needs_closest(i) { projected.get_closest(axes_shape.point(i)) } else { (0.0, 0.0) };
+        let axis = axes_shape.get_axis(i, closest);
+
+        let length = f32::sqrt((axis.0 * axis.0) + (axis.1 * axis.1));
+        if length > f32::EPSILON { Some((axis.0 / length, axis.1 / length)) } else { None }
+
+    });
+
+}
+
+fn midpoint(left: (f32, f32), right: (f32, f32)) -> (f32, f32)
+{
+
+    return ((left.0 + right.0) / 2.0, (left.1 + right.1) / 2.0);
+
+}
+
+//The point on the line through center along axis whose projection onto axis is value, shifted sideways by offset
+fn along(center: (f32, f32), axis: (f32, f32), value: f32, offset: f32) -> (f32, f32)
+{
+
+    let shift = value - ((center.0 * axis.0) + (center.1 * axis.1));
+
+    return (center.0 + (axis.0 * shift) - (axis.1 * offset), center.1 + (axis.1 * shift) + (axis.0 * offset));
+
+}
+
+#[cfg(test)]
+mod debug_tests
+{
+
+    use super::*;
+    use crate::float_equal;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_outline_polygon()
+    {
+
+        let triangle = Polygon::from_vertices((1.0, 1.0), vec![(0.0, 0.0), (2.0, 0.0), (0.0, 1.0)]);
+        let outline = outline(&triangle);
+
+        //Every vertex of the triangle must appear in the outline
+        for vertex in [(1.0, 1.0), (3.0, 1.0), (1.0, 2.0)]
+        {
+
+            assert!(outline.iter().any(|point| float_equal(point.0, vertex.0) && float_equal(point.1, vertex.1)));
+
+        }
+
+        //No point may lie outside of the triangle
+        for point in outline.iter()
+        {
+
+            assert!(point.0 > 1.0 - 0.0001 && point.1 > 1.0 - 0.0001);
+            assert!(point.0 + 2.0 * point.1 < 5.0 + 0.0001);
+
+        }
+
+    }
+
+    #[test]
+    fn test_outline_circle()
+    {
+
+        let circle = Circle::new((2.0, -1.0), 3.0);
+        let outline = outline(&circle);
+
+        assert!(outline.len() >= OUTLINE_SEGMENTS);
+
+        for point in outline.iter()
+        {
+
+            let distance = f32::sqrt((point.0 - 2.0) * (point.0 - 2.0) + (point.1 + 1.0) * (point.1 + 1.0));
+            assert!(distance > 3.0 - 0.0001 && distance < 3.0 * 1.01);
+
+        }
+
+    }
+
+    #[test]
+    fn test_collision_svg()
+    {
+
+        let square = AABB::new((0.0, 0.0), 4.0, 4.0);
+        let circle = Circle::new((4.5, 2.0), 1.0);
+
+        let mut svg = SvgDebug::new();
+        svg.collision(&square, &circle);
+
+        let document = svg.to_svg();
+
+        assert!(document.starts_with("<svg"));
+        assert!(document.trim_end().ends_with("</svg>"));
+        //Outline fill and stroke, the position marker, and one projection for each of the three axes
+        assert_eq!(document.matches(LEFT_COLOR).count(), 2 + 1 + 3);
+        assert!(document.contains(NORMAL_COLOR));
+        assert!(document.contains(RESOLUTION_COLOR));
+
+    }
+
+    #[test]
+    fn test_no_collision_svg()
+    {
+
+        let square = AABB::new((0.0, 0.0), 4.0, 4.0);
+        let circle = Circle::new((8.0, 2.0), 1.0);
+
+        let mut svg = SvgDebug::new();
+        svg.collision(&square, &circle);
+
+        let document = svg.to_svg();
+
+        assert!(!document.contains(NORMAL_COLOR));
+        assert!(!document.contains(RESOLUTION_COLOR));
+
+    }
+
+}
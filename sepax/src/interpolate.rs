@@ -0,0 +1,386 @@
+//! Interpolation between two states of a shape, e.g. the shape at the previous and current
+//! fixed simulation tick, so that it can be drawn smoothly in between.
+//!
+//! The two states are expected to be the same shape, moved and rotated. Positions are
+//! interpolated linearly, and rotation is interpolated along the shortest angle between the
+//! orientations of the two states, so the interpolated shape keeps its form instead of
+//! shrinking the way a vertex-by-vertex blend would.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//!
+//! let previous = Capsule::new((0.0, 0.0), (2.0, 0.0), 1.0);
+//! let current = Capsule::new((4.0, 2.0), (0.0, 2.0), 1.0);
+//!
+//! let drawn = previous.interpolate(&current, 0.5);
+//!
+//! assert!((drawn.position.0 - 2.0).abs() < 0.0001 && (drawn.position.1 - 1.0).abs() < 0.0001);
+//! assert!((drawn.arm().0 - f32::sqrt(2.0)).abs() < 0.0001 && (drawn.arm().1 - f32::sqrt(2.0)).abs() < 0.0001);
+//! ```
+
+use crate::polygon::Polygon;
+use crate::vertices::Vertices;
+use crate::circle::Circle;
+use crate::aabb::AABB;
+use crate::capsule::Capsule;
+use crate::parallelogram::Parallelogram;
+use crate::point::Point;
+use crate::segment::Segment;
+use crate::collider::Collider;
+use crate::transform::Transform2D;
+use crate::math;
+
+/// A trait for shapes which can be interpolated between two of their states. Applicable to
+/// all shapes.
+pub trait Interpolate
+{
+
+    /// The state a fraction t of the way from this state to the next one, where t = 0 gives
+    /// this state and t = 1 gives the next one.
+    fn interpolate(&self, next: &Self, t: f32) -> Self;
+
+}
+
+/// Linearly interpolate between two points.
+pub fn lerp(start: (f32, f32), end: (f32, f32), t: f32) -> (f32, f32)
+{
+
+    return (start.0 + (end.0 - start.0) * t, start.1 + (end.1 - start.1) * t);
+
+}
+
+/// Interpolate between two angles in radians, turning the shortest way around.
+pub fn lerp_angle(start: f32, end: f32, t: f32) -> f32
+{
+
+    let difference = math::rem_euclid(end - start + core::f32::consts::PI, core::f32::consts::TAU) - core::f32::consts::PI;
+
+    return start + difference * t;
+
+}
+
+//Rotate vector the fraction t of the shortest angle from start to end, and blend its length the same way
+fn turn(vector: (f32, f32), start: (f32, f32), end: (f32, f32), t: f32) -> (f32, f32)
+{
+
+    let cross = (start.0 * end.1) - (start.1 * end.0);
+    let dot = (start.0 * end.0) + (start.1 * end.1);
+
+    let angle = math::atan2(cross, dot) * t;
+    let rotated = crate::rotate!(math::sin(angle), math::cos(angle), vector);
+
+    let start_length = math::sqrt((start.0 * start.0) + (start.1 * start.1));
+    let end_length = math::sqrt((end.0 * end.0) + (end.1 * end.1));
+
+    if start_length <= f32::EPSILON
+    {
+
+        return rotated;
+
+    }
+
+    let scale = (start_length + (end_length - start_length) * t) / start_length;
+
+    return (rotated.0 * scale, rotated.1 * scale);
+
+}
+
+impl Interpolate for Polygon
+{
+
+    /// If the two polygons have a different number of vertices, the vertices snap to
+    /// those of the next polygon halfway through.
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let position = lerp(self.position, next.position, t);
+
+        if self.vertices.len() != next.vertices.len()
+        {
+
+            let vertices = if t < 0.5 { self.vertices.clone() } else { next.vertices.clone() };
+            return Polygon::from_vertices(position, vertices);
+
+        }
+
+        //The vertex furthest from the position gives the most precise orientation
+        let reference = (0..self.vertices.len()).max_by(|a, b|
+        {
+
+            let length = |vertex: (f32, f32)| (vertex.0 * vertex.0) + (vertex.1 * vertex.1);
+            length(self.vertices[*a]).total_cmp(&length(self.vertices[*b]))
+
+        });
+
+        let vertices = match reference
+        {
+
+            Some(index) =>
+            {
+
+                let (start, end) = (self.vertices[index], next.vertices[index]);
+                self.vertices.iter().map(|vertex| turn(*vertex, start, end, t)).collect()
+
+            },
+            None => Vertices::new()
+
+        };
+
+        return Polygon::from_vertices(position, vertices);
+
+    }
+
+}
+
+impl Interpolate for Circle
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        return Circle::new(lerp(self.position, next.position, t), self.radius + (next.radius - self.radius) * t);
+
+    }
+
+}
+
+impl Interpolate for AABB
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let width = self.width + (next.width - self.width) * t;
+        let height = self.height + (next.height - self.height) * t;
+
+        return AABB::new(lerp(self.position, next.position, t), width, height);
+
+    }
+
+}
+
+impl Interpolate for Capsule
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let arm = turn(self.arm(), self.arm(), next.arm(), t);
+
+        return Capsule::new(lerp(self.position, next.position, t), arm, self.radius + (next.radius - self.radius) * t);
+
+    }
+
+}
+
+impl Interpolate for Parallelogram
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let u = turn(self.u, self.u, next.u, t);
+        let v = turn(self.v, self.u, next.u, t);
+
+        return Parallelogram::new(lerp(self.position, next.position, t), u, v);
+
+    }
+
+}
+
+impl Interpolate for Point
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        return Point::new(lerp(self.position, next.position, t));
+
+    }
+
+}
+
+impl Interpolate for Segment
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let vector = turn(self.vector, self.vector, next.vector, t);
+
+        return Segment::from_vector(lerp(self.position, next.position, t), vector);
+
+    }
+
+}
+
+impl Interpolate for Collider
+{
+
+    /// If the two colliders hold different types of shape, the shape snaps to the next one
+    /// halfway through.
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        return match (self, next)
+        {
+
+            (Collider::Polygon(a), Collider::Polygon(b)) => Collider::Polygon(a.interpolate(b, t)),
+            (Collider::Circle(a), Collider::Circle(b)) => Collider::Circle(a.interpolate(b, t)),
+            (Collider::AABB(a), Collider::AABB(b)) => Collider::AABB(a.interpolate(b, t)),
+            (Collider::Capsule(a), Collider::Capsule(b)) => Collider::Capsule(a.interpolate(b, t)),
+            (Collider::Parallelogram(a), Collider::Parallelogram(b)) => Collider::Parallelogram(a.interpolate(b, t)),
+            (Collider::Point(a), Collider::Point(b)) => Collider::Point(a.interpolate(b, t)),
+            (Collider::Segment(a), Collider::Segment(b)) => Collider::Segment(a.interpolate(b, t)),
+            _ => if t < 0.5 { self.clone() } else { next.clone() }
+
+        };
+
+    }
+
+}
+
+impl Interpolate for Transform2D
+{
+
+    fn interpolate(&self, next: &Self, t: f32) -> Self
+    {
+
+        let rotation = lerp_angle(self.rotation, next.rotation, t);
+
+        return Transform2D::new(lerp(self.translation, next.translation, t), rotation, self.scale + (next.scale - self.scale) * t);
+
+    }
+
+}
+
+#[cfg(test)]
+mod interpolate_tests
+{
+
+    use super::*;
+    use crate::{float_equal, Rotate};
+
+    fn point_equal(left: (f32, f32), right: (f32, f32)) -> bool
+    {
+
+        return (left.0 - right.0).abs() < 0.0001 && (left.1 - right.1).abs() < 0.0001;
+
+    }
+
+    #[test]
+    fn test_lerp_angle()
+    {
+
+        let quarter = std::f32::consts::FRAC_PI_2;
+
+        assert!(float_equal(lerp_angle(0.0, quarter, 0.5), quarter * 0.5));
+
+        //Across the wrap around, the short way is through zero
+        let angle = lerp_angle(-0.25, std::f32::consts::TAU - 0.25, 0.5);
+        assert!(float_equal(angle, -0.25));
+
+        let angle = lerp_angle(3.0, -3.0, 0.5);
+        assert!(float_equal(angle.rem_euclid(std::f32::consts::TAU), std::f32::consts::PI));
+
+    }
+
+    #[test]
+    fn test_endpoints()
+    {
+
+        let mut previous = Polygon::from_vertices((1.0, 1.0), [(0.0, 0.0), (2.0, 0.0), (1.0, 2.0)].into());
+        let mut current = previous.clone();
+        current.position = (3.0, -1.0);
+        current.rotate(2.0);
+
+        let start = previous.interpolate(&current, 0.0);
+        let end = previous.interpolate(&current, 1.0);
+
+        for i in 0..3
+        {
+
+            assert!(point_equal(start.vertices[i], previous.vertices[i]));
+            assert!(point_equal(end.vertices[i], current.vertices[i]));
+
+        }
+
+        assert!(point_equal(end.position, (3.0, -1.0)));
+
+        previous.vertices.push((0.0, 1.0));
+        assert_eq!(previous.interpolate(&current, 0.25).vertices.len(), 4);
+        assert_eq!(previous.interpolate(&current, 0.75).vertices.len(), 3);
+
+    }
+
+    #[test]
+    fn test_rotation_keeps_shape()
+    {
+
+        let previous = Parallelogram::rectangle((0.0, 0.0), 4.0, 2.0);
+        let mut current = previous;
+        current.rotate(std::f32::consts::FRAC_PI_2);
+
+        let middle = previous.interpolate(&current, 0.5);
+
+        let diagonal = f32::sqrt(2.0);
+        assert!(point_equal(middle.u, (2.0 * diagonal, 2.0 * diagonal)));
+        assert!(point_equal(middle.v, (-diagonal, diagonal)));
+
+        let square = Polygon::from_vertices((0.0, 0.0), [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)].into());
+        let mut turned = square.clone();
+        turned.rotate(std::f32::consts::FRAC_PI_2);
+
+        let middle = square.interpolate(&turned, 0.5);
+        assert!(middle.vertices.iter().all(|vertex| float_equal((vertex.0 * vertex.0) + (vertex.1 * vertex.1), 2.0)));
+        assert!(point_equal(middle.vertices[0], (0.0, f32::sqrt(2.0))));
+
+    }
+
+    #[test]
+    fn test_round_shapes()
+    {
+
+        let circle = Circle::new((0.0, 0.0), 1.0).interpolate(&Circle::new((4.0, 0.0), 3.0), 0.25);
+        assert!(point_equal(circle.position, (1.0, 0.0)));
+        assert!(float_equal(circle.radius, 1.5));
+
+        let capsule = Capsule::new((0.0, 0.0), (1.0, 0.0), 1.0).interpolate(&Capsule::new((0.0, 0.0), (-3.0, 0.0), 1.0), 0.5);
+        assert!(point_equal(capsule.arm(), (0.0, 2.0)));
+
+    }
+
+    #[test]
+    fn test_collider()
+    {
+
+        let previous = Collider::from(Segment::new((0.0, 0.0), (2.0, 0.0)));
+        let current = Collider::from(Segment::new((2.0, 0.0), (2.0, 2.0)));
+
+        assert!(matches!(previous.interpolate(&current, 0.5), Collider::Segment(segment) if point_equal(segment.position, (1.0, 0.0))));
+
+        let point = Collider::from(Point::new((5.0, 5.0)));
+
+        assert!(matches!(previous.interpolate(&point, 0.25), Collider::Segment(_)));
+        assert!(matches!(previous.interpolate(&point, 0.75), Collider::Point(_)));
+
+    }
+
+    #[test]
+    fn test_transform()
+    {
+
+        let previous = Transform2D::new((0.0, 0.0), 3.0, 1.0);
+        let current = Transform2D::new((2.0, 4.0), -3.0, 3.0);
+
+        let middle = previous.interpolate(&current, 0.5);
+
+        assert!(point_equal(middle.translation, (1.0, 2.0)));
+        assert!(float_equal(middle.rotation.rem_euclid(std::f32::consts::TAU), std::f32::consts::PI));
+        assert!(float_equal(middle.scale, 2.0));
+
+    }
+
+}
@@ -0,0 +1,1181 @@
+//! [![Crates.io](https://img.shields.io/crates/v/sepax2d.svg)](https://crates.io/crates/sepax2d)
+//! [![MIT/Apache 2.0](https://img.shields.io/badge/license-MIT%2FApache-blue.svg)](./LICENSE)
+//!
+//! # sepax2d
+//! A safe Rust crate for finding and resolving collisions of convex shapes using the Separating Axis Theorem (SAT) in two dimensions.
+//!
+//! ### Usage
+//!
+//! Add the following to the `[dependencies]` section of your `Cargo.toml`:
+//!
+//! ```toml
+//! sepax2d = "0.3"
+//! ```
+//!
+//! Import the types of shapes you'd like to use and create some new shapes:
+//!
+//! ```rust
+//! use sepax2d::prelude::*;
+//! #
+//! # let top_left = (1.0, 1.0);
+//! # let width = 5.0;
+//! # let height = 5.0;
+//! # let center = (2.0, 0.0);
+//! # let radius = 2.0;
+//! # let arm = (1.0, 0.0);
+//! # let position = (-1.0, -1.0);
+//! # let u_vector = (2.0, 1.0);
+//! # let v_vector = (0.0, -2.0);
+//!
+//! let rectangle = AABB::new(top_left, width, height);
+//! let circle = Circle::new(center, radius);
+//! let capsule = Capsule::new(center, arm, radius);
+//! let parallelogram = Parallelogram::new(position, u_vector, v_vector);
+//!
+//! //The vertices of a polygon are position vectors
+//! //relative to the shape's position, i.e. if position
+//! //is (1, 2), then the absolute location of the
+//! //first vertex is (1, 0).
+//! let triangle = Polygon::from_vertices
+//! (
+//!
+//!     position,
+//!     [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into()
+//!
+//! );
+//! ```
+//!
+//! Use the `sat_overlap(&left, &right)` method to get a `bool` indicating whether or not the two given shapes overlap.
+//! Any struct implementing the `Shape` trait can be used.
+//!
+//! ```rust
+//! # use sepax2d::prelude::*;
+//! #
+//! # let top_left = (1.0, 1.0);
+//! # let width = 5.0;
+//! # let height = 5.0;
+//! # let center = (2.0, 0.0);
+//! # let radius = 2.0;
+//! # let arm = (1.0, 0.0);
+//! # let position = (-1.0, -1.0);
+//! # let rectangle = AABB::new(top_left, width, height);
+//! # let circle = Circle::new(center, radius);
+//! # let capsule = Capsule::new(center, arm, radius);
+//! #
+//! # let triangle = Polygon::from_vertices
+//! # (
+//! #
+//! #    position,
+//! #    [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into()
+//! #
+//! # );
+//! #
+//! assert!(sat_overlap(&circle, &capsule));
+//! assert!(!sat_overlap(&triangle, &rectangle));
+//! ```
+//!
+//! Use the `sat_collision(&left, &right)` method to get a `(f32, f32)` which represents the shift needed to add to `right`'s
+//! position in order to resolve the overlap of the two shapes.
+//!
+//! ```rust
+//! # use sepax2d::prelude::*;
+//! #
+//! # let top_left = (1.0, 1.0);
+//! # let width = 5.0;
+//! # let height = 5.0;
+//! # let center = (2.0, 0.0);
+//! # let radius = 2.0;
+//! # let arm = (1.0, 0.0);
+//! # let position = (-1.0, -1.0);
+//! #
+//! # let rectangle = AABB::new(top_left, width, height);
+//! # let circle = Circle::new(center, radius);
+//! # let capsule = Capsule::new(center, arm, radius);
+//! #
+//! # let mut triangle = Polygon::from_vertices
+//! # (
+//! #
+//! #    position,
+//! #    [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into()
+//! #
+//! # );
+//! #
+//! let resolution = sat_collision(&circle, &triangle);
+//!
+//! let position = triangle.position();
+//! triangle.set_position((position.0 + resolution.0, position.1 + resolution.1));
+//!
+//! assert!(!sat_overlap(&circle, &triangle));
+//! ```
+//!
+//! Use the `contains_point(&shape, point)` method to get a `bool` indicating whether or not the specified point
+//! is inside the given shape.
+//!
+//! ```rust
+//! # use sepax2d::prelude::*;
+//!
+//! let rect = AABB::new((0.0, 0.0), 5.0, 2.0);
+//!
+//! assert!(contains_point(&rect, (1.0, 1.0)));
+//! assert!(!contains_point(&rect, (10.0, -1.0)));
+//! ```
+//!
+//! Shapes implementing the `Distance` trait also support the `signed_distance(&shape, point)` and `closest_point(&shape, point)`
+//! methods, which measure how far a point is from the boundary of a shape (negative inside) and find the nearest boundary point
+//! and its outward normal, without allocating.
+//!
+//! The `Point` and `Segment` shapes have no area, and work with all of the methods above. Touching counts as overlapping
+//! along any direction in which one of the shapes has no width, so points overlap everything they touch, including the
+//! boundaries of other shapes, and segments overlap anything touching them from the side.
+//!
+//! `Polygon`, `Circle`, `Capsule`, `Parallelogram`, `Point`, and `Segment` shapes implement the `Rotate` trait, which allows you to rotate them
+//! around their `position`, or around any other pivot with `rotate_about`.
+//!
+//! ```rust
+//! # use sepax2d::prelude::*;
+//! # let position = (-1.0, -1.0);
+//!
+//! let mut triangle = Polygon::from_vertices
+//! (
+//!
+//!    position,
+//!    [(-1.0, 0.0), (0.0, 2.0), (1.0, 0.0)].into()
+//!
+//! );
+//!
+//! triangle.rotate(std::f32::consts::FRAC_PI_2)
+//! //New vertices: [(0.0, -1.0), (-2.0, 0.0), (0.0, 1.0)]
+//! ```
+//!
+//! A `Transform2D` combines a translation, rotation, and uniform scale. Every shape implements the `Transformable` trait to
+//! create a transformed copy of itself, and `Transformed` applies a transform lazily without copying or mutating the shape.
+//! Shapes and transforms also implement the `Interpolate` trait, to draw them smoothly between two simulation steps.
+//!
+//! The `builders` module creates regular polygons, rotated and rounded rectangles, and circle approximations, as well as
+//! arcs, rings, and stars split into convex pieces. All of them have counterclockwise winding.
+//!
+//! You can use the `intersects_line`, `intersects_ray`, and `intersects_segment` methods to
+//! check whether a shape intersects with the corresponding type of line.
+//!
+//! ```rust
+//! # use sepax2d::prelude::*;
+//!
+//! let triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (1.0, 1.0), (-1.0, 1.0)].into());
+//!
+//! assert!(intersects_segment(&triangle, (2.0, 0.5), (-2.0, 0.5)));
+//! ```
+//!
+//! ### Features
+//!
+//! Enable the `serde` feature for (De)Serialization of supported shapes! The `Collider` enum wraps any
+//! of the shapes, and its documentation describes the RON and JSON formats used to author hitboxes in files.
+//!
+//! sepax2d supports `no_std`. Disable the default `std` feature and enable `libm`, which then provides the
+//! square roots and trigonometry. The `alloc` feature keeps polygons backed by a `Vec` along with the modules
+//! that build lists of shapes or pairs: `boolean`, `batch`, `broadphase`, `visibility`, `tilemap`, and the arc, ring,
+//! and star builders. Without it, polygons store up to `vertices::MAX_VERTICES` vertices inline. The `debug` and `oracle` modules require `std`.
+//!
+//! ```toml
+//! sepax2d = { version = "0.3", default-features = false, features = ["libm"] }
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![allow(clippy::needless_return)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("sepax2d needs either the `std` or the `libm` feature for its float math");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+//Some of the functions are only used by modules which need alloc
+#[allow(dead_code)]
+mod math;
+
+pub mod vertices;
+pub mod polygon;
+pub mod circle;
+pub mod aabb;
+pub mod capsule;
+pub mod parallelogram;
+pub mod point;
+pub mod segment;
+
+pub mod line;
+#[cfg(feature = "alloc")]
+pub mod boolean;
+#[cfg(feature = "alloc")]
+pub mod batch;
+#[cfg(feature = "alloc")]
+pub mod broadphase;
+#[cfg(feature = "alloc")]
+pub mod visibility;
+#[cfg(feature = "alloc")]
+pub mod tilemap;
+pub mod transform;
+pub mod interpolate;
+pub mod builders;
+#[cfg(feature = "std")]
+pub mod oracle;
+
+pub mod collider;
+
+#[cfg(feature = "std")]
+pub mod debug;
+
+/// A trait describing the behavior needed to implement SAT overlap and collision
+/// for a given shape.
+pub trait Shape
+{
+
+    /// The location of the shape in 2D space.
+    fn position(&self) -> (f32, f32);
+
+    /// Set the location of the shape.
+    fn set_position(&mut self, position: (f32, f32));
+
+    /// The number of axes the shape provides for testing. For polygons, it is
+    /// the same as the number of vertices, but for circles it is simply one.
+    fn num_axes(&self) -> usize;
+
+    /// The method used to access the axes during the SAT calculations. This is
+    /// used to avoid the memory allocation of a new vector or array each time
+    /// we calculate collisions.
+    fn get_axis(&self, index: usize, target: (f32, f32)) -> (f32, f32);
+
+    /// Getting the minimum and maximum projection of the shape onto the given axis
+    /// to look for overlap. Normalize denotes whether or not the axis passed in is
+    /// a unit vector to avoid repeating calculations.
+    fn project(&self, axis: (f32, f32), normalize: bool) -> (f32, f32);
+
+    /// Determine whether or not the shape needs access to the closest vertex of
+    /// another shape to check collisions.
+    fn needs_closest(&self, index: usize) -> bool;
+
+    /// Gets the closest vertex/primary point/position to the given target, NOT the closest point
+    /// on the shape.
+    fn get_closest(&self, target: (f32, f32)) -> (f32, f32);
+
+    /// The point corresponding to the given axis, if applicable. Otherwise, position.
+    fn point(&self, index: usize) -> (f32, f32);
+
+}
+
+/// A trait indicating that a shape can be rotated around its position. Applicable
+/// to all shapes other than AABB.
+pub trait Rotate
+{
+
+    /// Rotate the shape by the given angle, with the rotation counterclockwise when
+    /// the Y-axis points up.
+    fn rotate(&mut self, angle: f32);
+
+    /// Rotate the shape using the given sine and cosine of an angle. Use this when
+    /// you are rotating multiple shapes by the same angle and don't want to re-calculate
+    /// the trig functions.
+    fn rotate_sincos(&mut self, sin: f32, cos: f32);
+
+    /// Rotate the shape by the given angle around the given pivot instead of its own position.
+    /// The shape's position moves around the pivot and the shape turns by the same angle.
+    fn rotate_about(&mut self, pivot: (f32, f32), angle: f32) where Self: Shape
+    {
+
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
+
+        self.rotate_about_sincos(pivot, sin, cos);
+
+    }
+
+    /// Rotate the shape around the given pivot using the given sine and cosine of an angle.
+    fn rotate_about_sincos(&mut self, pivot: (f32, f32), sin: f32, cos: f32) where Self: Shape
+    {
+
+        let position = self.position();
+        let offset = crate::rotate!(sin, cos, (position.0 - pivot.0, position.1 - pivot.1));
+
+        self.set_position((pivot.0 + offset.0, pivot.1 + offset.1));
+        self.rotate_sincos(sin, cos);
+
+    }
+
+}
+
+/// A trait for shapes which can find the closest point on their boundary to any point,
+/// used to compute signed distances. Applicable to all shapes.
+pub trait Distance
+{
+
+    /// Returns the point on the boundary of the shape closest to the given point, the outward
+    /// unit normal of the boundary there, and the signed distance from the boundary to the
+    /// given point, which is negative when the point is inside the shape.
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32);
+
+}
+
+//Helper macro to rotate the given 2D vector v by the rotation matrix with sine s and cosine c
+#[macro_export]
+macro_rules! rotate
+{
+
+    ($s: expr, $c: expr, $v: expr) =>
+    {
+
+        ($c * $v.0 - $s * $v.1, $s * $v.0 + $c * $v.1   )
+
+    };
+
+}
+
+/// Returns true if the given shapes overlap, and false if they do not. Does not work for
+/// degenerate polygons; use the `Point` and `Segment` shapes for shapes without area.
+///
+/// This method performs a floating point comparison with Rust's built in epsilon constant, so it may
+/// return the incorrect answer for shapes which are very small or very close together.
+///
+/// Requires both shapes to be convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let square = Polygon::from_vertices((1.0, 1.0), [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)].into());
+/// let triangle = Polygon::from_vertices((0.0, 0.0), [(2.0, 2.0), (0.0, -2.0), (-1.0, 0.0)].into());
+///
+/// assert!(sat_overlap(&square, &triangle));
+/// ```
+pub fn sat_overlap(left: &(impl Shape + ?Sized), right: &(impl Shape + ?Sized)) -> bool
+{
+
+    if !shape_overlap(left, right, false).0
+    {
+
+        return false;
+
+    }
+
+    if !shape_overlap(right, left, false).0
+    {
+
+        return false;
+
+    }
+
+    return true;
+
+}
+
+/// Returns the vector that needs to be added to the second shape's position to resolve a collision with
+/// the first shape. Does not work for degenerate polygons; use the `Point` and `Segment` shapes for
+/// shapes without area.
+///
+/// If the shapes are not colliding, it returns the zero vector.
+///
+/// This method performs a floating point comparison with Rust's built in epsilon constant, so it may
+/// return the incorrect answer for shapes which are very small or very close together.
+///
+/// Requires both shapes to be convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let square = Polygon::from_vertices((1.0, 1.0), [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)].into());
+/// let triangle = Polygon::from_vertices((-3.5, 1.0), [(4.0, 0.0), (0.0, 6.0), (-4.0, 0.0)].into());
+///
+/// let aabb = AABB::new((0.0, 2.0), 2.0, 2.0);
+///
+/// let resolution = sat_collision(&square, &triangle);
+/// //resolution = (-0.5, 0.0) up to floating point error
+///
+/// assert!(resolution.0 + 0.5 < f32::EPSILON && resolution.0 + 0.5 > -f32::EPSILON);
+/// assert!(resolution.1 < f32::EPSILON && resolution.1 > -f32::EPSILON);
+///
+/// let aabb_resolution = sat_collision(&aabb, &triangle);
+/// //resolution = (-0.5, 0.0) up to floating point error. aabb is a different way to
+/// //represent the same shape as square
+///
+/// assert!(resolution.0 + 0.5 < f32::EPSILON && resolution.0 + 0.5 > -f32::EPSILON);
+/// assert!(resolution.1 < f32::EPSILON && resolution.1 > -f32::EPSILON);
+///
+/// ```
+pub fn sat_collision(left: &(impl Shape + ?Sized), right: &(impl Shape + ?Sized)) -> (f32, f32)
+{
+
+    let l_overlap = shape_overlap(left, right, true);
+    let r_overlap = shape_overlap(right, left, true);
+
+    //Ensure that the vector points from left to right
+    let r_flipped = (true, r_overlap.1, (-r_overlap.2.0, -r_overlap.2.1));
+
+    let overlap = if l_overlap.1 < r_flipped.1 { l_overlap } else { r_flipped };
+
+    return (overlap.1 * overlap.2.0, overlap.1 * overlap.2.1);
+
+}
+
+/// Returns true if the given shape contains the specified point, and false if
+/// it does not. Points on the boundary of the shape are contained. Does not work
+/// for degenerate polygons.
+///
+/// This method performs a floating point comparison with Rust's built in epsilon constant, so it may
+/// return the incorrect answer for shapes which are very small or very close to the point.
+///
+/// Requires the shape to be convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let square = Polygon::from_vertices((1.0, 1.0), [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)].into());
+/// let triangle = Polygon::from_vertices((0.0, 0.0), [(2.0, 2.0), (0.0, -2.0), (-1.0, 0.0)].into());
+///
+/// assert!(contains_point(&triangle, (0.5, 0.5)));
+/// assert!(!contains_point(&square, (-2.0, 2.0)));
+/// ```
+pub fn contains_point(shape: &(impl Shape + ?Sized), point: (f32, f32)) -> bool
+{
+
+    return shape_overlap(shape, &point::Point::new(point), false).0;
+
+}
+
+/// Returns the distance from the boundary of the given shape to the specified point. The
+/// distance is negative when the point is inside the shape, and positive when it is outside.
+///
+/// Requires the shape to be convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let circle = Circle::new((0.0, 0.0), 2.0);
+/// let square = AABB::new((0.0, 0.0), 4.0, 4.0);
+///
+/// assert!((signed_distance(&circle, (3.0, 0.0)) - 1.0).abs() < 0.0001);
+/// assert!((signed_distance(&square, (1.0, 2.0)) + 1.0).abs() < 0.0001);
+/// ```
+pub fn signed_distance(shape: &(impl Distance + ?Sized), point: (f32, f32)) -> f32
+{
+
+    return shape.boundary(point).2;
+
+}
+
+/// Returns the point on the boundary of the given shape closest to the specified point, along
+/// with the outward unit normal of the boundary there. This works whether the point is inside
+/// or outside of the shape.
+///
+/// Requires the shape to be convex.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let square = AABB::new((0.0, 0.0), 4.0, 4.0);
+///
+/// let (point, normal) = closest_point(&square, (1.0, 2.0));
+///
+/// assert!((point.0 - 0.0).abs() < 0.0001 && (point.1 - 2.0).abs() < 0.0001);
+/// assert!((normal.0 + 1.0).abs() < 0.0001 && normal.1.abs() < 0.0001);
+/// ```
+pub fn closest_point(shape: &(impl Distance + ?Sized), point: (f32, f32)) -> ((f32, f32), (f32, f32))
+{
+
+    let (closest, normal, _distance) = shape.boundary(point);
+
+    return (closest, normal);
+
+}
+
+fn shape_overlap(axes: &(impl Shape + ?Sized), projected: &(impl Shape + ?Sized), normalize: bool) -> (bool, f32, (f32, f32))
+{
+
+    let mut min_overlap = f32::MAX;
+    let mut min_axis = (0.0, 0.0);
+
+    let num_axes = axes.num_axes();
+    for i in 0..num_axes
+    {
+
+        let closest = if axes.needs_closest(i) { projected.get_closest(axes.point(i)) } else { (0.0, 0.0) };
+        let mut axis = axes.get_axis(i, closest);
+
+        //A zero axis, e.g. from a circle to a point at its center, cannot separate anything
+        if axis.0 == 0.0 && axis.1 == 0.0
+        {
+
+            continue;
+
+        }
+
+        //If we are just checking for overlap, we can skip normalizing the axis. However,
+        //we need to normalize to find the minimum penetration vector.
+        if normalize
+        {
+
+            let length = math::sqrt((axis.0 * axis.0) + (axis.1 * axis.1));
+                    
+            if length > f32::EPSILON
+            {
+
+                axis = (axis.0 / length, axis.1 / length);
+
+            }
+
+        }
+
+        let (min_l, max_l) = axes.project(axis, normalize);
+        let (min_r, max_r) = projected.project(axis, normalize);
+
+        //If there is no overlap, we can return early
+        if separated((min_l, max_l), (min_r, max_r))
+        {
+
+            return (false, 0.0, (0.0, 0.0));
+
+        }
+
+        //Point the axis in the direction which pushes the projected shape out the shorter way
+        let forward = max_l - min_r;
+        let backward = max_r - min_l;
+        let overlap = f32::min(forward, backward);
+
+        if overlap < min_overlap
+        {
+
+            min_overlap = overlap;
+            min_axis = if forward <= backward { axis } else { (-axis.0, -axis.1) };
+
+        }
+
+    }
+
+    return (true, min_overlap, min_axis);
+
+}
+
+//Whether two projections onto an axis are apart. Touching shapes do not overlap, unless one has
+//no width along the axis, like a point or a segment along its normal, since it could then only
+//ever meet the other by touching
+pub(crate) fn separated((min_l, max_l): (f32, f32), (min_r, max_r): (f32, f32)) -> bool
+{
+
+    let scale = f32::max(1.0, f32::max(max_l.abs(), max_r.abs())) * f32::EPSILON;
+    let tolerance = if max_l - min_l < scale || max_r - min_r < scale { -scale } else { f32::EPSILON };
+
+    return min_l > max_r - tolerance || min_r > max_l - tolerance;
+
+}
+
+fn project(position: (f32, f32), axis: (f32, f32), points: &[(f32, f32)]) -> (f32, f32)
+{
+
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+
+    for (x, y) in points
+    {
+
+        let position = (position.0 + *x, position.1 + *y);
+
+        let projection = (position.0 * axis.0) + (position.1 * axis.1);
+
+        min = f32::min(min, projection);
+        max = f32::max(max, projection);
+
+    }
+
+    return (min, max);
+
+}
+
+fn closest(position: (f32, f32), target: (f32, f32), points: &[(f32, f32)]) -> (f32, f32)
+{
+
+    let mut point = (0.0, 0.0);
+    let mut min = f32::MAX;
+
+    for (x, y) in points
+    {
+
+        let position = (position.0 + *x, position.1 + *y);
+        let dist_square = (position.0 - target.0) * (position.0 - target.0) + (position.1 - target.1) * (position.1 - target.1);
+
+        if dist_square < min
+        {
+
+            point = position;
+            min = dist_square;
+
+        }
+
+    }
+
+    return point;
+
+}
+
+//The closest point on the segment between start and end to the given point
+fn closest_on_segment(start: (f32, f32), end: (f32, f32), point: (f32, f32)) -> (f32, f32)
+{
+
+    let side = (end.0 - start.0, end.1 - start.1);
+    let length_square = (side.0 * side.0) + (side.1 * side.1);
+
+    if length_square < f32::EPSILON
+    {
+
+        return start;
+
+    }
+
+    let t = (((point.0 - start.0) * side.0) + ((point.1 - start.1) * side.1)) / length_square;
+    let t = t.clamp(0.0, 1.0);
+
+    return (start.0 + side.0 * t, start.1 + side.1 * t);
+
+}
+
+//Boundary of a disc of the given radius around the given center, shared by circles and capsules
+fn round_boundary(center: (f32, f32), radius: f32, point: (f32, f32), fallback: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+{
+
+    let difference = (point.0 - center.0, point.1 - center.1);
+    let length = math::sqrt((difference.0 * difference.0) + (difference.1 * difference.1));
+
+    //Any direction is valid at the exact center, so use the one provided by the shape
+    let normal = if length > f32::EPSILON { (difference.0 / length, difference.1 / length) } else { fallback };
+
+    return ((center.0 + normal.0 * radius, center.1 + normal.1 * radius), normal, length - radius);
+
+}
+
+//Boundary of the convex polygon with the given vertices relative to position, in either orientation
+fn polygon_boundary(position: (f32, f32), points: &[(f32, f32)], point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+{
+
+    if points.is_empty()
+    {
+
+        return round_boundary(position, 0.0, point, (1.0, 0.0));
+
+    }
+
+    //Determine the orientation without allocating, so that the outward normal is known for every side
+    let mut area = 0.0;
+    for i in 0..points.len()
+    {
+
+        let current = points[i];
+        let next = points[(i + 1) % points.len()];
+
+        area += (current.0 * next.1) - (next.0 * current.1);
+
+    }
+
+    let orientation = if area < 0.0 { -1.0 } else { 1.0 };
+
+    let mut inside = points.len() > 2;
+    let mut min = f32::MAX;
+    let mut closest = position;
+    let mut normal = (1.0, 0.0);
+
+    for i in 0..points.len()
+    {
+
+        let start = (position.0 + points[i].0, position.1 + points[i].1);
+        let next = points[(i + 1) % points.len()];
+        let end = (position.0 + next.0, position.1 + next.1);
+        let side = (end.0 - start.0, end.1 - start.1);
+
+        let cross = ((side.0 * (point.1 - start.1)) - (side.1 * (point.0 - start.0))) * orientation;
+        if cross < 0.0
+        {
+
+            inside = false;
+
+        }
+
+        let candidate = closest_on_segment(start, end, point);
+        let dist_square = (point.0 - candidate.0) * (point.0 - candidate.0) + (point.1 - candidate.1) * (point.1 - candidate.1);
+
+        if dist_square < min
+        {
+
+            min = dist_square;
+            closest = candidate;
+
+            let length = math::sqrt((side.0 * side.0) + (side.1 * side.1));
+            if length > f32::EPSILON
+            {
+
+                normal = ((side.1 * orientation) / length, (-side.0 * orientation) / length);
+
+            }
+
+        }
+
+    }
+
+    let distance = math::sqrt(min);
+
+    //Outside of the polygon, the normal points from the closest point towards the target, which
+    //also covers the regions around the vertices where the side normals do not apply
+    if !inside && distance > f32::EPSILON
+    {
+
+        normal = ((point.0 - closest.0) / distance, (point.1 - closest.1) / distance);
+
+    }
+
+    return (closest, normal, if inside { -distance } else { distance });
+
+}
+
+#[allow(dead_code)]
+fn float_equal(left: f32, right: f32) -> bool
+{
+
+    return (left - right).abs() < 0.00001;
+
+}
+
+#[cfg(test)]
+mod sat_tests
+{
+
+    use super::*;
+    use super::prelude::*;
+
+    #[test]
+    fn test_sat_overlap()
+    {
+
+        //Polygons
+        let square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)].into());
+        let triangle = Polygon::from_vertices((2.0, 2.0), [(-1.0, 1.0), (0.0, -1.0), (1.0, 1.0)].into());
+        let pentagon = Polygon::from_vertices((-3.0, 0.0), [(2.0, 0.0), (4.0, 1.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)].into());
+        let triangle2 = Polygon::from_vertices((4.0, 3.0), [(-2.0, 1.0), (-1.0, -2.0), (2.0, 0.0)].into());
+
+        assert!(sat_overlap(&pentagon, &pentagon));
+        assert!(sat_overlap(&square, &triangle));
+        assert!(sat_overlap(&triangle, &square));
+        assert!(sat_overlap(&square, &pentagon));
+        assert!(sat_overlap(&pentagon, &square));
+        assert!(sat_overlap(&square, &triangle2));
+        assert!(sat_overlap(&triangle2, &square));
+        assert!(sat_overlap(&triangle, &triangle2));
+        assert!(sat_overlap(&triangle, &triangle2));
+
+        //Circles
+        let circle1 = Circle::new((-1.0, -1.0), 2.0);
+        let circle2 = Circle::new((-2.0, -1.0), 1.1);
+
+        assert!(sat_overlap(&circle1, &square));
+        assert!(sat_overlap(&circle2, &pentagon));
+        assert!(sat_overlap(&pentagon, &circle1));
+        assert!(sat_overlap(&circle1, &circle2));
+
+    }
+
+    #[test]
+    fn test_sat_no_overlap()
+    {
+
+        //Polygons
+        let triangle = Polygon::from_vertices((2.0, 2.0), [(-1.0, 1.0), (0.0, -1.0), (1.0, 1.0)].into());
+        let pentagon = Polygon::from_vertices((-3.0, 0.0), [(2.0, 0.0), (4.0, 1.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)].into());
+        let triangle2 = Polygon::from_vertices((4.0, 3.0), [(-2.0, 1.0), (-1.0, -2.0), (2.0, 0.0)].into());
+
+        assert!(!sat_overlap(&pentagon, &triangle));
+        assert!(!sat_overlap(&triangle, &pentagon));
+        assert!(!sat_overlap(&pentagon, &triangle2));
+        assert!(!sat_overlap(&triangle2, &pentagon));
+
+        //Circles
+        let circle1 = Circle::new((-1.0, -1.0), 2.0);
+        let circle2 = Circle::new((-2.0, -1.0), 1.1);
+        let circle3 = Circle::new((2.0, 5.0), 1.0);
+
+        assert!(!sat_overlap(&triangle, &circle1));
+        assert!(!sat_overlap(&circle2, &triangle2));
+        assert!(!sat_overlap(&circle1, &circle3));
+        assert!(!sat_overlap(&circle3, &circle2));
+
+    }
+
+    #[test]
+    fn test_sat_collision()
+    {
+
+        //Polygons
+        let square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)].into());
+        let rectangle = Polygon::from_vertices((1.0, -2.0), [(0.0, 0.0), (1.0, 0.0), (1.0, 2.1), (0.0, 2.1)].into());
+        let triangle = Polygon::from_vertices((0.0, 0.0), [(0.5, 0.6), (0.0, -1.0), (-0.5, 0.6)].into());
+        let triangle2 = Polygon::from_vertices((-0.4, -0.4), [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].into());
+
+        let resolution = sat_collision(&square, &rectangle);
+        assert!(float_equal(resolution.0, 0.0));
+        assert!(float_equal(resolution.1, -0.1));
+
+        let resolution1 = sat_collision(&rectangle, &square);
+        assert!(float_equal(resolution1.0, 0.0));
+        assert!(float_equal(resolution1.1, 0.1));
+
+        let resolution2 = sat_collision(&square, &triangle);
+        assert!(float_equal(resolution2.0, -0.5));
+        assert!(float_equal(resolution2.1, 0.0));
+
+        let resolution3 = sat_collision(&square, &triangle2);
+        assert!(float_equal((resolution3.0 * -1.0) + (resolution3.1), 0.0)); //Assert that the minimum axis of penetration is (1,1)
+
+        //Circles
+        let circle = Circle::new((2.0, -0.5), 1.0);
+        let circle2 = Circle::new((0.0, -0.5), 1.1);
+
+        let resolution4 = sat_collision(&circle, &square);
+        assert!(float_equal(resolution4.0, 0.0));
+        assert!(float_equal(resolution4.1, 0.5));
+
+        let resolution5 = sat_collision(&circle, &triangle2);
+        assert!(float_equal(resolution5.0, 0.0));
+        assert!(float_equal(resolution5.1, 0.0));
+
+        let resolution6 = sat_collision(&circle2, &circle);
+        assert!(float_equal(resolution6.0, 0.1));
+        assert!(float_equal(resolution6.1, 0.0));
+
+    }
+
+    #[test]
+    fn test_capsule_collision()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (0.0, 2.0), 2.0);
+
+        let triangle = Polygon::from_vertices((0.0, 5.0), [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into());
+        let rectangle = AABB::new((-4.0, 4.0), 2.5, 0.5);
+        let circle1 = Circle::new((2.0, -2.5), 1.0);
+        let circle2 = Circle::new((3.0, 0.0), 1.5);
+
+        assert!(sat_overlap(&capsule, &circle1));
+        assert!(!sat_overlap(&rectangle, &capsule));
+
+        let resolution1 = sat_collision(&circle2, &capsule);
+        let resolution2 = sat_collision(&capsule, &triangle);
+
+        assert!(float_equal(resolution1.0, -0.5));
+        assert!(float_equal(resolution1.1, 0.0));
+        assert!(float_equal(resolution2.0, 0.0));
+        assert!(float_equal(resolution2.1, 1.0));
+
+    }
+
+    #[test]
+    fn test_parallelogram_collision()
+    {
+
+        let gram = Parallelogram::new((0.0, -0.5), (2.0, 1.0), (-1.0, -1.0));
+
+        let triangle = Polygon::from_vertices((0.0, 5.0), [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into());
+        let rectangle = AABB::new((-1.0, 0.0), 4.0, 2.0);
+        let circle = Circle::new((2.0, -2.5), 1.0);
+
+        assert!(!sat_overlap(&gram, &triangle));
+        assert!(!sat_overlap(&gram, &circle));
+
+        assert!(sat_overlap(&gram, &rectangle));
+
+        let resolution = sat_collision(&rectangle, &gram);
+        println!("{:?}", resolution);
+        
+        assert!(float_equal(resolution.0, 0.0));
+        assert!(float_equal(resolution.1, -0.5));
+
+    }
+
+    #[test]
+    fn test_contains_point()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (0.0, 2.0), 2.0);
+        let triangle = Polygon::from_vertices((0.0, 5.0), [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into());
+        let rectangle = AABB::new((-4.0, 4.0), 2.5, 0.5);
+        let circle = Circle::new((2.0, -2.5), 1.0);
+
+        assert!(contains_point(&capsule, (1.0, 1.0)));
+        assert!(contains_point(&triangle, (0.0, 5.0)));
+        assert!(contains_point(&rectangle, (-2.0, 4.1)));
+        assert!(contains_point(&circle, (2.5, -3.0)));
+
+
+        assert!(!contains_point(&capsule, (2.0, 4.0)));
+        assert!(!contains_point(&triangle, (0.0, 0.0)));
+        assert!(!contains_point(&rectangle, (-4.0, 3.9)));
+        assert!(!contains_point(&circle, (1.5, -3.5)));
+
+    }
+
+    #[test]
+    fn test_point_overlap()
+    {
+
+        let shapes: Vec<collider::Collider> = vec!
+        [
+
+            Polygon::from_vertices((0.0, 0.0), [(-2.0, -2.0), (2.0, -2.0), (0.0, 2.0)].into()).into(),
+            Circle::new((0.0, 0.0), 2.0).into(),
+            AABB::new((-2.0, -2.0), 4.0, 4.0).into(),
+            Capsule::new((0.0, 0.0), (0.0, 1.0), 1.5).into(),
+            Parallelogram::new((-2.0, -2.0), (4.0, 0.0), (1.0, 4.0)).into(),
+            Segment::new((-2.0, 0.0), (2.0, 0.0)).into()
+
+        ];
+
+        for shape in shapes.iter()
+        {
+
+            //Inside, including the center of round shapes where their axis has no direction
+            let inside = Point::new((0.0, 0.0));
+            assert!(sat_overlap(shape, &inside));
+            assert!(sat_overlap(&inside, shape));
+            assert!(contains_point(shape, (0.0, 0.0)));
+
+            let outside = Point::new((5.0, 5.0));
+            assert!(!sat_overlap(shape, &outside));
+            assert!(!sat_overlap(&outside, shape));
+            assert!(!contains_point(shape, (5.0, 5.0)));
+
+        }
+
+        //Points on the boundary touch the shape
+        let square = AABB::new((0.0, 0.0), 2.0, 2.0);
+        assert!(sat_overlap(&square, &Point::new((2.0, 1.0))));
+        assert!(sat_overlap(&Point::new((0.0, 0.0)), &square));
+        assert!(!sat_overlap(&square, &Point::new((2.1, 1.0))));
+
+        assert!(sat_overlap(&Point::new((1.0, 2.0)), &Point::new((1.0, 2.0))));
+        assert!(!sat_overlap(&Point::new((1.0, 2.0)), &Point::new((1.0, 2.1))));
+
+        //Resolving pushes the point's partner out to the boundary
+        let resolution = sat_collision(&Point::new((1.5, 1.0)), &square);
+        assert!(float_equal(resolution.0, -0.5));
+        assert!(float_equal(resolution.1, 0.0));
+
+        let circle = Circle::new((3.0, 4.0), 1.0);
+        let resolution = sat_collision(&circle, &Point::new((3.5, 4.0)));
+        assert!(float_equal(resolution.0, 0.5));
+        assert!(float_equal(resolution.1, 0.0));
+
+    }
+
+    #[test]
+    fn test_segment_overlap()
+    {
+
+        let segment = Segment::new((0.0, 0.0), (4.0, 4.0));
+
+        //Crossing, touching from the side, and parallel
+        assert!(sat_overlap(&segment, &Segment::new((0.0, 4.0), (4.0, 0.0))));
+        assert!(sat_overlap(&segment, &Segment::new((2.0, 2.0), (4.0, 0.0))));
+        assert!(!sat_overlap(&segment, &Segment::new((1.0, 0.0), (5.0, 4.0))));
+        assert!(!sat_overlap(&segment, &Segment::new((0.0, 4.0), (1.9, 2.1))));
+
+        //Collinear segments only overlap when their ranges overlap, and meeting end to end is
+        //only touching, like the corners of two polygons
+        assert!(sat_overlap(&segment, &Segment::new((3.0, 3.0), (6.0, 6.0))));
+        assert!(!sat_overlap(&segment, &Segment::new((-2.0, -2.0), (0.0, 0.0))));
+        assert!(!sat_overlap(&segment, &Segment::new((5.0, 5.0), (6.0, 6.0))));
+
+        //Points along the segment and its extension
+        assert!(sat_overlap(&segment, &Point::new((2.0, 2.0))));
+        assert!(sat_overlap(&Point::new((4.0, 4.0)), &segment));
+        assert!(!sat_overlap(&segment, &Point::new((5.0, 5.0))));
+        assert!(!sat_overlap(&segment, &Point::new((2.0, 2.5))));
+
+        //Shapes with area
+        assert!(sat_overlap(&segment, &Circle::new((4.0, 0.0), 2.9)));
+        assert!(!sat_overlap(&segment, &Circle::new((4.0, 0.0), 2.8)));
+        assert!(sat_overlap(&Circle::new((6.0, 5.0), 2.3), &segment));
+        assert!(!sat_overlap(&Circle::new((6.0, 5.0), 2.2), &segment));
+        assert!(sat_overlap(&segment, &AABB::new((3.0, 0.0), 1.0, 3.5)));
+        assert!(!sat_overlap(&segment, &AABB::new((3.5, 0.0), 1.0, 3.0)));
+        assert!(sat_overlap(&Capsule::new((0.0, 2.0), (1.0, 0.0), 1.0), &segment));
+        assert!(!sat_overlap(&Capsule::new((0.0, 2.0), (1.0, 0.0), 0.5), &segment));
+
+        //The corner of the box pokes through the segment, so it is pushed along its normal
+        let resolution = sat_collision(&AABB::new((3.0, 0.0), 2.0, 3.5), &segment);
+        assert!(float_equal(resolution.0, -0.25));
+        assert!(float_equal(resolution.1, 0.25));
+
+    }
+
+    #[test]
+    fn test_coincident_centers()
+    {
+
+        let small = Circle::new((1.0, 1.0), 1.0);
+        let large = Circle::new((1.0, 1.0), 3.0);
+
+        assert!(sat_overlap(&small, &large));
+
+        //A circle centered exactly on a vertex
+        let square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into());
+        let corner = Circle::new((2.0, 2.0), 0.5);
+
+        assert!(sat_overlap(&square, &corner));
+        assert!(sat_overlap(&corner, &square));
+
+    }
+
+    #[test]
+    fn test_resolution_direction()
+    {
+
+        //The resolution must follow the overlap even when the positions suggest the other way
+        let floor = AABB::new((0.0, 0.0), 4.0, 1.0);
+        let mut pillar = Polygon::from_vertices((5.0, 5.0), [(-4.5, -7.0), (-3.5, -7.0), (-3.5, -4.2), (-4.5, -4.2)].into());
+
+        let resolution = sat_collision(&floor, &pillar);
+
+        assert!(float_equal(resolution.0, 0.0));
+        assert!(float_equal(resolution.1, -0.8));
+
+        pillar.position = (pillar.position.0 + resolution.0, pillar.position.1 + resolution.1);
+
+        assert!(!sat_overlap(&floor, &pillar));
+
+    }
+
+    #[test]
+    fn test_signed_distance()
+    {
+
+        let capsule = Capsule::new((0.0, 0.0), (0.0, 2.0), 2.0);
+        let triangle = Polygon::from_vertices((0.0, 5.0), [(0.0, -2.0), (-1.0, 2.0), (1.0, 2.0)].into());
+        let rectangle = AABB::new((-4.0, 4.0), 2.5, 0.5);
+        let circle = Circle::new((2.0, -2.5), 1.0);
+
+        assert!(float_equal(signed_distance(&capsule, (1.0, 1.0)), -1.0));
+        assert!(float_equal(signed_distance(&capsule, (0.0, 7.0)), 3.0));
+        assert!(float_equal(signed_distance(&rectangle, (-2.0, 4.1)), -0.1));
+        assert!(float_equal(signed_distance(&rectangle, (-4.0, 3.9)), 0.1));
+        assert!(float_equal(signed_distance(&circle, (2.0, -2.5)), -1.0));
+        assert!(float_equal(signed_distance(&triangle, (0.0, 8.0)), 1.0));
+
+        //Outside of a corner, the distance is measured to the vertex itself
+        assert!(float_equal(signed_distance(&rectangle, (-5.0, 3.0)), f32::sqrt(2.0)));
+
+        //The sign must agree with contains_point
+        for point in [(1.0, 1.0), (0.0, 5.0), (-2.0, 4.1), (2.5, -3.0), (2.0, 4.0), (0.0, 0.0), (-4.0, 3.9), (1.5, -3.5)]
+        {
+
+            assert_eq!(signed_distance(&capsule, point) < 0.0, contains_point(&capsule, point));
+            assert_eq!(signed_distance(&triangle, point) < 0.0, contains_point(&triangle, point));
+            assert_eq!(signed_distance(&rectangle, point) < 0.0, contains_point(&rectangle, point));
+            assert_eq!(signed_distance(&circle, point) < 0.0, contains_point(&circle, point));
+
+        }
+
+    }
+
+    #[test]
+    fn test_closest_point()
+    {
+
+        let triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)].into());
+        let clockwise = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (0.0, 4.0), (4.0, 0.0)].into());
+
+        for shape in [&triangle, &clockwise]
+        {
+
+            let (point, normal) = closest_point(shape, (1.0, -2.0));
+            assert!(float_equal(point.0, 1.0) && float_equal(point.1, 0.0));
+            assert!(float_equal(normal.0, 0.0) && float_equal(normal.1, -1.0));
+
+            let (point, normal) = closest_point(shape, (1.0, 0.5));
+            assert!(float_equal(point.0, 1.0) && float_equal(point.1, 0.0));
+            assert!(float_equal(normal.0, 0.0) && float_equal(normal.1, -1.0));
+
+            let (point, normal) = closest_point(shape, (3.0, 3.0));
+            assert!(float_equal(point.0, 2.0) && float_equal(point.1, 2.0));
+            assert!(float_equal(normal.0, 1.0 / f32::sqrt(2.0)) && float_equal(normal.1, 1.0 / f32::sqrt(2.0)));
+
+            let (point, normal) = closest_point(shape, (-1.0, -1.0));
+            assert!(float_equal(point.0, 0.0) && float_equal(point.1, 0.0));
+            assert!(float_equal(normal.0, -1.0 / f32::sqrt(2.0)) && float_equal(normal.1, -1.0 / f32::sqrt(2.0)));
+
+        }
+
+    }
+
+    #[test]
+    fn test_rotate()
+    {
+
+        let mut triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (2.0, 0.0), (0.0, 1.0)].into());
+        let mut capsule = Capsule::new((2.0, 1.0), (2.0, 0.0), 4.0);
+        let mut gram = Parallelogram::new((3.0, 4.0), (2.0, 1.0), (-1.0, 1.0));
+
+        capsule.rotate(std::f32::consts::FRAC_PI_4);
+
+        assert!(float_equal(capsule.arm().0, 2.0 / f32::sqrt(2.0)));
+        assert!(float_equal(capsule.arm().1, 2.0 / f32::sqrt(2.0)));
+        assert!(float_equal(capsule.perp().0, -4.0 / f32::sqrt(2.0)));
+        assert!(float_equal(capsule.perp().1, 4.0 / f32::sqrt(2.0)));
+
+        let sin = f32::sin(std::f32::consts::PI);
+        let cos = f32::cos(std::f32::consts::PI);
+
+        triangle.rotate_sincos(sin, cos);
+
+        assert!(float_equal(triangle.vertices[1].0, -2.0));
+        assert!(float_equal(triangle.vertices[1].1, 0.0));
+        assert!(float_equal(triangle.vertices[2].0, 0.0));
+        assert!(float_equal(triangle.vertices[2].1, -1.0));
+
+        gram.rotate_sincos(sin, cos);
+
+        assert!(float_equal(gram.u.0, -2.0));
+        assert!(float_equal(gram.u.1, -1.0));
+        assert!(float_equal(gram.v.0, 1.0));
+        assert!(float_equal(gram.v.1, -1.0));
+
+    }
+
+}
+
+pub mod prelude
+{
+
+    pub use crate::{sat_overlap, sat_collision, contains_point};
+    pub use crate::{signed_distance, closest_point};
+
+    pub use crate::Shape;
+    pub use crate::Rotate;
+    pub use crate::Distance;
+
+    pub use crate::polygon::Polygon;
+    pub use crate::circle::Circle;
+    pub use crate::aabb::AABB;
+    pub use crate::capsule::Capsule;
+    pub use crate::parallelogram::Parallelogram;
+    pub use crate::point::Point;
+    pub use crate::segment::Segment;
+
+    pub use crate::collider::Collider;
+
+    pub use crate::transform::{Transform2D, Transformable, Transformed};
+    pub use crate::interpolate::Interpolate;
+
+    pub use crate::line::intersects_line;
+    pub use crate::line::intersects_ray;
+    pub use crate::line::intersects_segment;
+
+}
\ No newline at end of file
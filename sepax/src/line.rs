@@ -1,4 +1,5 @@
 use crate::Shape;
+use crate::math;
 
 /// Checks if the given shape intersects the infinite line located at
 /// line_position pointing in the direction of the vector line_direction.
@@ -38,13 +39,14 @@ pub fn intersects_ray(shape: &(impl Shape + ?Sized), ray_position: (f32, f32), r
             if normalized == (0.0, 0.0)
             {
              
-                let length = f32::sqrt(ray_direction.0 * ray_direction.0 + ray_direction.1 * ray_direction.1);
+                let length = math::sqrt(ray_direction.0 * ray_direction.0 + ray_direction.1 * ray_direction.1);
                 normalized = (ray_direction.0 / length, ray_direction.1 / length);
                 
             }
             
+            //Closest point on the ray to the shape's point, measured from the ray's start
             let point = shape.point(i);
-            let distance = (point.0 * normalized.0) + (point.1 * normalized.1);
+            let distance = ((point.0 - ray_position.0) * normalized.0) + ((point.1 - ray_position.1) * normalized.1);
             
             if distance < f32::EPSILON
             {
@@ -103,6 +105,7 @@ pub fn intersects_segment(shape: &(impl Shape + ?Sized), line_start: (f32, f32),
     }
     
     let mut normalized = (0.0, 0.0);
+    let mut length = 0.0;
     
     let num_axes = shape.num_axes();
     for i in 0..num_axes
@@ -114,13 +117,14 @@ pub fn intersects_segment(shape: &(impl Shape + ?Sized), line_start: (f32, f32),
             if normalized == (0.0, 0.0)
             {
              
-                let length = f32::sqrt(direction.0 * direction.0 + direction.1 * direction.1);
+                length = math::sqrt(direction.0 * direction.0 + direction.1 * direction.1);
                 normalized = (direction.0 / length, direction.1 / length);
                 
             }
             
+            //Closest point on the segment to the shape's point, measured from the segment's start
             let point = shape.point(i);
-            let distance = (point.0 * normalized.0) + (point.1 * normalized.1);
+            let distance = ((point.0 - line_start.0) * normalized.0) + ((point.1 - line_start.1) * normalized.1);
             
             if distance < f32::EPSILON
             {
@@ -128,7 +132,7 @@ pub fn intersects_segment(shape: &(impl Shape + ?Sized), line_start: (f32, f32),
                 line_start
                 
             }
-            else if distance > 1.0 + f32::EPSILON
+            else if distance > length - f32::EPSILON
             {
                 
                 line_end
@@ -178,7 +182,7 @@ mod line_tests
     fn test_line_intersection()
     {
 
-        let triangle = Polygon::from_vertices((0.0, 0.0), vec![(0.0, 0.0), (-1.0, 1.0), (1.0, 1.0)]);
+        let triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (-1.0, 1.0), (1.0, 1.0)].into());
         let circle = Circle::new((2.0, 2.0), 1.0);
         let capsule = Capsule::new((10.0, 5.0), (-3.0, 2.0), 2.0);
 
@@ -200,7 +204,7 @@ mod line_tests
     fn test_ray_intersection()
     {
         
-        let triangle = Polygon::from_vertices((0.0, 0.0), vec![(0.0, 0.0), (-1.0, 1.0), (1.0, 1.0)]);
+        let triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (-1.0, 1.0), (1.0, 1.0)].into());
         let circle = Circle::new((2.0, 2.0), 1.0);
         let capsule = Capsule::new((10.0, 5.0), (-3.0, 2.0), 2.0);
 
@@ -226,7 +230,7 @@ mod line_tests
     fn test_segment_intersection()
     {
         
-        let pentagon = Polygon::from_vertices((0.0, 0.0), vec![(0.0, 0.0), (1.0, 0.0), (2.0, 1.0), (0.5, 2.0), (-1.0, 1.0)]);
+        let pentagon = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (1.0, 0.0), (2.0, 1.0), (0.5, 2.0), (-1.0, 1.0)].into());
         let circle = Circle::new((0.0, 0.0), 1.0);
         let gram = Parallelogram::new((2.0, 3.0), (1.0, 2.0), (2.0, 1.0));
         
@@ -244,4 +248,21 @@ mod line_tests
         
     }
 
+    #[test]
+    fn test_round_intersection_away_from_origin()
+    {
+
+        let circle = Circle::new((-0.4, 1.75), 1.0);
+        let capsule = Capsule::new((10.0, 10.0), (0.0, 2.0), 1.0);
+
+        assert!(!intersects_segment(&circle, (-0.93, 4.02), (0.95, 9.03)));
+        assert!(intersects_segment(&circle, (-3.0, 2.0), (3.0, 2.0)));
+        assert!(!intersects_ray(&circle, (-0.93, 4.02), (1.88, 5.01)));
+
+        assert!(!intersects_segment(&capsule, (11.5, 13.5), (13.0, 12.0)));
+        assert!(intersects_segment(&capsule, (11.5, 13.5), (10.0, 12.5)));
+        assert!(!intersects_ray(&capsule, (11.5, 13.5), (1.0, -1.0)));
+
+    }
+
 }
\ No newline at end of file
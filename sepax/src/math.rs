@@ -0,0 +1,139 @@
+//! The float functions that `core` does not provide. They come from `std` when it is
+//! enabled and from `libm` otherwise, so that results only depend on the chosen backend.
+
+#[cfg(feature = "std")]
+pub fn sqrt(x: f32) -> f32
+{
+
+    return f32::sqrt(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sqrt(x: f32) -> f32
+{
+
+    return libm::sqrtf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn sin(x: f32) -> f32
+{
+
+    return f32::sin(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sin(x: f32) -> f32
+{
+
+    return libm::sinf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn cos(x: f32) -> f32
+{
+
+    return f32::cos(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn cos(x: f32) -> f32
+{
+
+    return libm::cosf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn sin_cos(x: f32) -> (f32, f32)
+{
+
+    return f32::sin_cos(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sin_cos(x: f32) -> (f32, f32)
+{
+
+    return libm::sincosf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn atan2(y: f32, x: f32) -> f32
+{
+
+    return f32::atan2(y, x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn atan2(y: f32, x: f32) -> f32
+{
+
+    return libm::atan2f(y, x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn floor(x: f32) -> f32
+{
+
+    return f32::floor(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn floor(x: f32) -> f32
+{
+
+    return libm::floorf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn ceil(x: f32) -> f32
+{
+
+    return f32::ceil(x);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn ceil(x: f32) -> f32
+{
+
+    return libm::ceilf(x);
+
+}
+
+#[cfg(feature = "std")]
+pub fn rem_euclid(x: f32, y: f32) -> f32
+{
+
+    return f32::rem_euclid(x, y);
+
+}
+
+#[cfg(not(feature = "std"))]
+pub fn rem_euclid(x: f32, y: f32) -> f32
+{
+
+    let remainder = libm::fmodf(x, y);
+
+    if remainder < 0.0
+    {
+
+        return remainder + y.abs();
+
+    }
+
+    return remainder;
+
+}
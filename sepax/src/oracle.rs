@@ -0,0 +1,601 @@
+//! A slow reference implementation of the collision queries, for testing the SAT code against.
+//!
+//! Every shape is modelled as a convex core, which is a polygon, a segment, or a single point,
+//! grown by a radius. Queries are answered by brute force in double precision: two cores overlap
+//! when any of their edges intersect or one contains a vertex of the other, their distance is the
+//! smallest distance between any two edges, and their penetration depth is the distance from the
+//! origin to the boundary of the convex hull of all differences between their vertices.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::oracle::Model;
+//!
+//! let square = Collider::from(AABB::new((0.0, 0.0), 2.0, 2.0));
+//! let circle = Collider::from(Circle::new((3.5, 1.0), 2.0));
+//!
+//! let left = Model::from(&square);
+//! let right = Model::from(&circle);
+//!
+//! //The circle reaches half a unit into the square
+//! assert!((left.separation(&right) + 0.5).abs() < 0.0001);
+//! assert!(sat_overlap(&square, &circle));
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::collider::Collider;
+
+/// A convex core given by its absolute vertices in either winding order, grown by a radius.
+#[derive(Clone, Debug)]
+pub struct Model
+{
+
+    pub points: Vec<(f64, f64)>,
+    pub radius: f64
+
+}
+
+impl Model
+{
+
+    /// Create a model of a single point.
+    pub fn point(point: (f32, f32)) -> Model
+    {
+
+        return Model { points: vec![widen(point)], radius: 0.0 };
+
+    }
+
+    /// Create a model of the segment between the given points.
+    pub fn segment(start: (f32, f32), end: (f32, f32)) -> Model
+    {
+
+        return Model { points: vec![widen(start), widen(end)], radius: 0.0 };
+
+    }
+
+    /// Returns the distance between the two shapes when they are apart, and minus the length of
+    /// the shortest translation separating them when they overlap.
+    pub fn separation(&self, other: &Model) -> f64
+    {
+
+        let radii = self.radius + other.radius;
+
+        if cores_intersect(&self.points, &other.points)
+        {
+
+            return -(radii + penetration(&self.points, &other.points));
+
+        }
+
+        return core_distance(&self.points, &other.points) - radii;
+
+    }
+
+    /// Returns the distance from the boundary of the shape to the given point, which is negative
+    /// when the point is inside the shape.
+    pub fn signed_distance(&self, point: (f32, f32)) -> f64
+    {
+
+        return self.separation(&Model::point(point));
+
+    }
+
+    /// Returns the separation between the shape and the given segment, as for `separation`.
+    pub fn segment_separation(&self, start: (f32, f32), end: (f32, f32)) -> f64
+    {
+
+        return self.separation(&Model::segment(start, end));
+
+    }
+
+    /// Returns the separation between the shape and the given ray, as for `separation`.
+    pub fn ray_separation(&self, position: (f32, f32), direction: (f32, f32)) -> f64
+    {
+
+        let end = self.beyond(position, direction);
+        return self.segment_separation(position, end);
+
+    }
+
+    /// Returns the separation between the shape and the given infinite line, as for `separation`.
+    pub fn line_separation(&self, position: (f32, f32), direction: (f32, f32)) -> f64
+    {
+
+        let start = self.beyond(position, (-direction.0, -direction.1));
+        let end = self.beyond(position, direction);
+
+        return self.segment_separation(start, end);
+
+    }
+
+    //A point along the given direction which is further from the position than any part of the shape
+    fn beyond(&self, position: (f32, f32), direction: (f32, f32)) -> (f32, f32)
+    {
+
+        let origin = widen(position);
+        let reach = self.points.iter().map(|point| length(sub(*point, origin))).fold(0.0, f64::max) + self.radius + 1.0;
+        let scale = reach / length(widen(direction));
+
+        return ((origin.0 + (direction.0 as f64 * scale)) as f32, (origin.1 + (direction.1 as f64 * scale)) as f32);
+
+    }
+
+}
+
+impl From<&Collider> for Model
+{
+
+    fn from(collider: &Collider) -> Model
+    {
+
+        let (position, points, radius): ((f32, f32), Vec<(f32, f32)>, f32) = match collider
+        {
+
+            Collider::Polygon(polygon) => (polygon.position, polygon.vertices.to_vec(), 0.0),
+            Collider::Circle(circle) => (circle.position, vec![(0.0, 0.0)], circle.radius),
+            Collider::AABB(aabb) => (aabb.position, vec![(0.0, 0.0), (aabb.width, 0.0), (aabb.width, aabb.height), (0.0, aabb.height)], 0.0),
+            Collider::Capsule(capsule) =>
+            {
+
+                let arm = capsule.arm();
+                (capsule.position, vec![(-arm.0, -arm.1), arm], capsule.radius)
+
+            },
+            Collider::Parallelogram(gram) => (gram.position, gram.points().to_vec(), 0.0),
+            Collider::Point(point) => (point.position, vec![(0.0, 0.0)], 0.0),
+            Collider::Segment(segment) => (segment.position, vec![(0.0, 0.0), segment.vector], 0.0)
+
+        };
+
+        let origin = widen(position);
+        let points = points.iter().map(|point| add(origin, widen(*point))).collect();
+
+        return Model { points, radius: radius as f64 };
+
+    }
+
+}
+
+fn widen(point: (f32, f32)) -> (f64, f64)
+{
+
+    return (point.0 as f64, point.1 as f64);
+
+}
+
+fn add(left: (f64, f64), right: (f64, f64)) -> (f64, f64)
+{
+
+    return (left.0 + right.0, left.1 + right.1);
+
+}
+
+fn sub(left: (f64, f64), right: (f64, f64)) -> (f64, f64)
+{
+
+    return (left.0 - right.0, left.1 - right.1);
+
+}
+
+fn dot(left: (f64, f64), right: (f64, f64)) -> f64
+{
+
+    return (left.0 * right.0) + (left.1 * right.1);
+
+}
+
+fn cross(left: (f64, f64), right: (f64, f64)) -> f64
+{
+
+    return (left.0 * right.1) - (left.1 * right.0);
+
+}
+
+fn length(vector: (f64, f64)) -> f64
+{
+
+    return dot(vector, vector).sqrt();
+
+}
+
+//The edges of a core, where a segment is a single edge and a point is an edge of no length
+fn edges(points: &[(f64, f64)]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_
+{
+
+    let count = if points.len() > 2 { points.len() } else { 1 };
+
+    return (0..count).map(move |i| (points[i], points[(i + 1) % points.len()]));
+
+}
+
+fn cores_intersect(left: &[(f64, f64)], right: &[(f64, f64)]) -> bool
+{
+
+    for (a, b) in edges(left)
+    {
+
+        for (c, d) in edges(right)
+        {
+
+            if segments_intersect(a, b, c, d)
+            {
+
+                return true;
+
+            }
+
+        }
+
+    }
+
+    return contains(left, right[0]) || contains(right, left[0]);
+
+}
+
+//Whether the point is inside or on a polygon of either winding. Points and segments contain nothing
+fn contains(polygon: &[(f64, f64)], point: (f64, f64)) -> bool
+{
+
+    if polygon.len() < 3
+    {
+
+        return false;
+
+    }
+
+    let mut positive = false;
+    let mut negative = false;
+
+    for (start, end) in edges(polygon)
+    {
+
+        let side = cross(sub(end, start), sub(point, start));
+
+        positive |= side > 0.0;
+        negative |= side < 0.0;
+
+    }
+
+    return !(positive && negative);
+
+}
+
+fn segments_intersect(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> bool
+{
+
+    return segment_distance(a, b, c, d) == 0.0;
+
+}
+
+fn core_distance(left: &[(f64, f64)], right: &[(f64, f64)]) -> f64
+{
+
+    let mut distance = f64::MAX;
+
+    for (a, b) in edges(left)
+    {
+
+        for (c, d) in edges(right)
+        {
+
+            distance = f64::min(distance, segment_distance(a, b, c, d));
+
+        }
+
+    }
+
+    return distance;
+
+}
+
+fn segment_distance(a: (f64, f64), b: (f64, f64), c: (f64, f64), d: (f64, f64)) -> f64
+{
+
+    let (ab, cd) = (sub(b, a), sub(d, c));
+    let denominator = cross(ab, cd);
+
+    if denominator != 0.0
+    {
+
+        let t = cross(sub(c, a), cd) / denominator;
+        let u = cross(sub(c, a), ab) / denominator;
+
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)
+        {
+
+            return 0.0;
+
+        }
+
+    }
+
+    //Segments which do not cross are closest at one of the four endpoints
+    let candidates = [point_segment_distance(a, c, d), point_segment_distance(b, c, d), point_segment_distance(c, a, b), point_segment_distance(d, a, b)];
+
+    return candidates.iter().fold(f64::MAX, |min, distance| f64::min(min, *distance));
+
+}
+
+fn point_segment_distance(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64
+{
+
+    let side = sub(end, start);
+    let squared = dot(side, side);
+
+    let t = if squared > 0.0 { (dot(sub(point, start), side) / squared).clamp(0.0, 1.0) } else { 0.0 };
+    let closest = (start.0 + (side.0 * t), start.1 + (side.1 * t));
+
+    return length(sub(point, closest));
+
+}
+
+//The depth of two intersecting cores, i.e. the distance from the origin to the boundary of their
+//Minkowski difference. Cores without area have no interior, so their depth is zero
+fn penetration(left: &[(f64, f64)], right: &[(f64, f64)]) -> f64
+{
+
+    let differences: Vec<(f64, f64)> = left.iter().flat_map(|a| right.iter().map(move |b| sub(*a, *b))).collect();
+    let hull = convex_hull(differences);
+
+    if hull.len() < 3
+    {
+
+        return 0.0;
+
+    }
+
+    return edges(&hull).map(|(start, end)| point_segment_distance((0.0, 0.0), start, end)).fold(f64::MAX, f64::min);
+
+}
+
+//Andrew's monotone chain, giving a counterclockwise hull without collinear points
+fn convex_hull(mut points: Vec<(f64, f64)>) -> Vec<(f64, f64)>
+{
+
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points.dedup();
+
+    if points.len() < 3
+    {
+
+        return points;
+
+    }
+
+    let mut hull: Vec<(f64, f64)> = Vec::with_capacity(points.len() * 2);
+
+    for pass in 0..2
+    {
+
+        let start = hull.len();
+
+        for point in points.iter()
+        {
+
+            while hull.len() >= start + 2 && cross(sub(hull[hull.len() - 1], hull[hull.len() - 2]), sub(*point, hull[hull.len() - 2])) <= 0.0
+            {
+
+                hull.pop();
+
+            }
+
+            hull.push(*point);
+
+        }
+
+        //The last point of each chain starts the other one
+        hull.pop();
+
+        if pass == 0
+        {
+
+            points.reverse();
+
+        }
+
+    }
+
+    return hull;
+
+}
+
+#[cfg(test)]
+mod oracle_tests
+{
+
+    use proptest::prelude::*;
+    use proptest::test_runner::Config;
+
+    use super::*;
+    use crate::prelude::*;
+    use crate::line::{intersects_line, intersects_ray, intersects_segment};
+
+    //Cases closer than this to touching are skipped, as the SAT code works in single precision
+    const MARGIN: f64 = 0.01;
+
+    //Points within the given distance of the origin along each axis. Packing shapes closer
+    //together makes overlapping pairs more common
+    fn point(spread: f32) -> impl Strategy<Value = (f32, f32)>
+    {
+
+        return (-spread..spread, -spread..spread);
+
+    }
+
+    fn vector() -> impl Strategy<Value = (f32, f32)>
+    {
+
+        return (0.0f32..core::f32::consts::TAU, 0.5f32..4.0).prop_map(|(angle, length)| (length * angle.cos(), length * angle.sin()));
+
+    }
+
+    //Convex polygons with vertices on a circle, in either winding order
+    fn polygon(spread: f32) -> impl Strategy<Value = Polygon>
+    {
+
+        return (point(spread), 0.5f32..4.0, prop::collection::vec(0.0f32..1.0, 3..8), any::<bool>()).prop_filter_map("polygon too thin", |(position, radius, steps, clockwise)|
+        {
+
+            let total: f32 = steps.iter().map(|step| step + 0.2).sum();
+            let mut angle: f32 = 0.0;
+            let mut vertices = Vec::new();
+
+            for step in steps.iter()
+            {
+
+                vertices.push((radius * angle.cos(), radius * angle.sin()));
+                angle += core::f32::consts::TAU * (step + 0.2) / total;
+
+            }
+
+            if clockwise
+            {
+
+                vertices.reverse();
+
+            }
+
+            let polygon = Polygon::from_vertices(position, vertices);
+            return if polygon.is_convex() { Some(polygon) } else { None };
+
+        });
+
+    }
+
+    fn collider(spread: f32) -> impl Strategy<Value = Collider>
+    {
+
+        return prop_oneof!
+        [
+
+            polygon(spread).prop_map(Collider::from),
+            (point(spread), 0.25f32..4.0).prop_map(|(position, radius)| Collider::from(Circle::new(position, radius))),
+            (point(spread), 0.5f32..6.0, 0.5f32..6.0).prop_map(|(position, width, height)| Collider::from(AABB::new(position, width, height))),
+            (point(spread), vector(), 0.25f32..2.0).prop_map(|(position, arm, radius)| Collider::from(Capsule::new(position, arm, radius))),
+            (point(spread), vector(), vector()).prop_filter("parallelogram too thin", |(_position, u, v)| (u.0 * v.1 - u.1 * v.0).abs() > 0.5)
+                .prop_map(|(position, u, v)| Collider::from(Parallelogram::new(position, u, v))),
+            point(spread).prop_map(|position| Collider::from(Point::new(position))),
+            (point(spread), vector()).prop_map(|(position, vector)| Collider::from(Segment::from_vector(position, vector)))
+
+        ];
+
+    }
+
+    fn moved(collider: &Collider, offset: (f32, f32)) -> Collider
+    {
+
+        let mut moved = collider.clone();
+        let position = moved.shape().position();
+        moved.shape_mut().set_position((position.0 + offset.0, position.1 + offset.1));
+
+        return moved;
+
+    }
+
+    #[test]
+    fn test_separation()
+    {
+
+        let square = Model::from(&Collider::from(AABB::new((0.0, 0.0), 4.0, 4.0)));
+        let inner = Model::from(&Collider::from(AABB::new((1.0, 1.5), 1.0, 1.0)));
+        let capsule = Model::from(&Collider::from(Capsule::new((7.0, 2.0), (0.0, 1.0), 1.0)));
+
+        assert!((square.separation(&inner) + 2.0).abs() < 1e-9);
+        assert!((square.separation(&capsule) - 2.0).abs() < 1e-9);
+        assert!((capsule.separation(&square) - 2.0).abs() < 1e-9);
+
+        assert!((square.signed_distance((2.0, 3.0)) + 1.0).abs() < 1e-9);
+        assert!((capsule.signed_distance((7.0, 5.0)) - 1.0).abs() < 1e-9);
+
+        assert!((square.segment_separation((5.0, -1.0), (5.0, 1.0)) - 1.0).abs() < 1e-9);
+        assert!((square.ray_separation((6.0, 2.0), (1.0, 0.0)) - 2.0).abs() < 1e-9);
+        assert!((square.line_separation((6.0, 1.0), (1.0, 0.0)) + 1.0).abs() < 1e-9);
+
+    }
+
+    proptest!
+    {
+
+        #![proptest_config(Config { cases: 2000, max_global_rejects: 20000, ..Config::default() })]
+
+        #[test]
+        fn prop_overlap(left in collider(8.0), right in collider(8.0))
+        {
+
+            let separation = Model::from(&left).separation(&Model::from(&right));
+            prop_assume!(separation.abs() > MARGIN);
+
+            prop_assert_eq!(sat_overlap(&left, &right), separation < 0.0);
+
+        }
+
+        #[test]
+        fn prop_collision(left in collider(2.0), right in collider(2.0))
+        {
+
+            let depth = -Model::from(&left).separation(&Model::from(&right));
+            prop_assume!(depth > MARGIN);
+
+            //The resolution must push the shapes apart. It can be longer than the depth, as the axes
+            //between rounded shapes only approximate the direction of least penetration
+            let resolution = sat_collision(&left, &right);
+            let distance = f64::hypot(resolution.0 as f64, resolution.1 as f64);
+            let resolved = Model::from(&left).separation(&Model::from(&moved(&right, resolution)));
+
+            prop_assert!(distance > depth - MARGIN, "resolution {} is shorter than the depth {}", distance, depth);
+            prop_assert!(resolved > -MARGIN, "shapes still overlap by {} after resolution", -resolved);
+
+        }
+
+        #[test]
+        fn prop_contains_point(shape in collider(8.0), target in point(8.0))
+        {
+
+            let distance = Model::from(&shape).signed_distance(target);
+            prop_assume!(distance.abs() > MARGIN);
+
+            prop_assert_eq!(contains_point(&shape, target), distance < 0.0);
+
+        }
+
+        #[test]
+        fn prop_lines(shape in collider(8.0), start in point(8.0), direction in vector())
+        {
+
+            let model = Model::from(&shape);
+            let end = (start.0 + direction.0, start.1 + direction.1);
+
+            let line = model.line_separation(start, direction);
+            let ray = model.ray_separation(start, direction);
+            let segment = model.segment_separation(start, end);
+
+            if line.abs() > MARGIN
+            {
+
+                prop_assert_eq!(intersects_line(&shape, start, direction), line < 0.0);
+
+            }
+
+            if ray.abs() > MARGIN
+            {
+
+                prop_assert_eq!(intersects_ray(&shape, start, direction), ray < 0.0);
+
+            }
+
+            if segment.abs() > MARGIN
+            {
+
+                prop_assert_eq!(intersects_segment(&shape, start, end), segment < 0.0);
+
+            }
+
+        }
+
+    }
+
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
+use crate::math;
+
 /// An parallelogram defined by two vectors. Degenerate parallelograms
 /// are not guaranteed to work properly.
 /// 
@@ -141,8 +143,8 @@ impl crate::Rotate for Parallelogram
     fn rotate(&mut self, angle: f32)
     {
 
-        let sin = f32::sin(angle);
-        let cos = f32::cos(angle);
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
 
         self.rotate_sincos(sin, cos);
 
@@ -158,12 +160,24 @@ impl crate::Rotate for Parallelogram
 
 }
 
+impl crate::Distance for Parallelogram
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return crate::polygon_boundary(self.position, &self.points(), point);
+
+    }
+
+}
+
 #[cfg(test)]
 mod paralellogram_tests
 {
 
     use super::*;
-    use crate::{float_equal, Shape};
+    use crate::{float_equal, Shape, Distance};
 
     #[test]
     fn test_num_axes()
@@ -220,4 +234,26 @@ mod paralellogram_tests
 
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_boundary()
+    {
+
+        let gram = Parallelogram::new((0.0, 0.0), (2.0, 0.0), (1.0, 1.0));
+
+        let (point, normal, distance) = gram.boundary((1.5, 2.0));
+
+        assert!(float_equal(point.0, 1.5));
+        assert!(float_equal(point.1, 1.0));
+        assert!(float_equal(normal.0, 0.0));
+        assert!(float_equal(normal.1, 1.0));
+        assert!(float_equal(distance, 1.0));
+
+        let (_, normal, distance) = gram.boundary((0.0, 1.0));
+
+        assert!(float_equal(normal.0, -1.0 / f32::sqrt(2.0)));
+        assert!(float_equal(normal.1, 1.0 / f32::sqrt(2.0)));
+        assert!(float_equal(distance, 1.0 / f32::sqrt(2.0)));
+
+    }
+
+}
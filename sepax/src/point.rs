@@ -0,0 +1,186 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// A single point, i.e. a shape with no area. Points overlap shapes which contain them,
+/// and other points at the same location.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let cursor = Point::new((1.0, 1.0));
+/// let square = AABB::new((0.0, 0.0), 2.0, 2.0);
+/// let circle = Circle::new((4.0, 0.0), 1.0);
+///
+/// assert!(sat_overlap(&cursor, &square));
+/// assert!(!sat_overlap(&cursor, &circle));
+/// assert!(sat_overlap(&cursor, &Point::new((1.0, 1.0))));
+///
+/// //Moves the square by one unit, so that the point is on its boundary
+/// let resolution = sat_collision(&cursor, &square);
+/// assert!((resolution.0.abs() + resolution.1.abs() - 1.0).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point
+{
+
+    pub position: (f32, f32)
+
+}
+
+impl Point
+{
+
+    /// Create a new point at the given position.
+    pub fn new(position: (f32, f32)) -> Point
+    {
+
+        return Point { position };
+
+    }
+
+}
+
+impl crate::Shape for Point
+{
+
+    fn position(&self) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+    fn set_position(&mut self, position: (f32, f32))
+    {
+
+        self.position = position;
+
+    }
+
+    fn num_axes(&self) -> usize
+    {
+
+        return 1;
+
+    }
+
+    fn get_axis(&self, _index: usize, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return (target.0 - self.position.0, target.1 - self.position.1);
+
+    }
+
+    fn project(&self, axis: (f32, f32), _normalize: bool) -> (f32, f32)
+    {
+
+        let projection = (self.position.0 * axis.0) + (self.position.1 * axis.1);
+
+        return (projection, projection);
+
+    }
+
+    fn needs_closest(&self, _index: usize) -> bool
+    {
+
+        return true;
+
+    }
+
+    fn get_closest(&self, _target: (f32, f32)) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+    fn point(&self, _index: usize) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+}
+
+impl crate::Rotate for Point
+{
+
+    fn rotate(&mut self, _angle: f32)
+    {
+
+    }
+
+    fn rotate_sincos(&mut self, _sin: f32, _cos: f32)
+    {
+
+    }
+
+}
+
+impl crate::Distance for Point
+{
+
+    /// The distance to a point is never negative, since a point has no inside.
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return crate::round_boundary(self.position, 0.0, point, (1.0, 0.0));
+
+    }
+
+}
+
+#[cfg(test)]
+mod point_tests
+{
+
+    use super::*;
+    use crate::{float_equal, Shape, Distance};
+
+    #[test]
+    fn test_get_axis()
+    {
+
+        let point = Point::new((1.0, 2.0));
+        let axis = point.get_axis(0, (3.0, -1.0));
+
+        assert!(float_equal(axis.0, 2.0));
+        assert!(float_equal(axis.1, -3.0));
+
+    }
+
+    #[test]
+    fn test_project()
+    {
+
+        let point = Point::new((1.0, 2.0));
+        let projection = point.project((2.0, 1.0), false);
+
+        assert!(float_equal(projection.0, 4.0));
+        assert!(float_equal(projection.1, 4.0));
+
+    }
+
+    #[test]
+    fn test_boundary()
+    {
+
+        let point = Point::new((1.0, 2.0));
+
+        let (closest, normal, distance) = point.boundary((4.0, 6.0));
+        assert_eq!(closest, (1.0, 2.0));
+        assert!(float_equal(normal.0, 0.6));
+        assert!(float_equal(normal.1, 0.8));
+        assert!(float_equal(distance, 5.0));
+
+        let (_, normal, distance) = point.boundary((1.0, 2.0));
+        assert_eq!(normal, (1.0, 0.0));
+        assert!(float_equal(distance, 0.0));
+
+    }
+
+}
@@ -0,0 +1,911 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use crate::math;
+use crate::vertices::Vertices;
+
+/// A polygon with a position and finitely many vertices given in either clockwise or
+/// counterclockwise orientation.
+/// 
+/// The polygon is represented as a star shape: to find the absolute position of the 
+/// n-th vertex, add the `position` to the n-th element of `vertices`.
+/// 
+/// The position is NOT used as a vertex by default: if you want your position to
+/// be a vertex, then define a vertex with offset `(0.0, 0.0)`.
+/// 
+/// The order of the vertices container determines the order in which the vertices are connected.
+/// Both clockwise and counterclockwise orientations are valid. For outlines imported from elsewhere,
+/// `normalize_winding`, `simplify`, and `recenter` make the winding consistent, remove redundant
+/// vertices, and move the position to the center.
+/// 
+/// Without the `alloc` feature the vertices are stored inline, so a polygon can have at most
+/// `MAX_VERTICES` of them. See the `vertices` module.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use sepax2d::prelude::*;
+/// 
+/// let mut triangle = Polygon::new((3.0, 2.0));
+/// triangle.add((-1.0, -1.0));
+/// triangle.add((0.0, 1.0));
+/// triangle.add((1.0, -1.0)); 
+/// //Triangle with vertices (2, 1), (3, 3), and (4, 1)
+/// 
+/// assert!(triangle.is_convex());
+/// 
+/// let vertices = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)];
+/// let rectangle = Polygon::from_vertices((0.0, 0.0), vertices.into()); 
+/// //Rectangle with vertices (0,0), (2,0), (2,1), and (0, 1)
+/// 
+/// assert!(rectangle.is_convex());
+/// 
+/// let concave_vertices = [(0.0, 0.0), (2.0, 0.0), (0.0, 1.0), (2.0, 1.0)];
+/// let concave_shape = Polygon::from_vertices((0.0, 0.0), concave_vertices.into()); 
+/// //Non-convex hour-glass shape with the same vertices
+/// 
+/// assert!(!concave_shape.is_convex());
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polygon
+{
+
+    pub position: (f32, f32),
+    pub vertices: Vertices
+
+}
+
+impl Polygon
+{
+
+    /// Define a new polygon with no vertices at the given position. 
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let point = Polygon::new((17.0, 5.0));
+    /// ```
+    pub fn new(position: (f32, f32)) -> Polygon
+    {
+
+        return Polygon { position, vertices: Vertices::new() };
+
+    }
+
+    /// Returns `true` when the polygon is convex, i.e. when none of its points lie on opposite sides of one
+    /// of its perimeter segements, and `false` when it is concave.
+    /// 
+    /// Returns true automatically when the shape has two or fewer vertices, as points and line segments are
+    /// trivially convex.
+    /// 
+    /// This method performs a floating point comparison with Rust's built in epsilon constant, so it may
+    /// return the incorrect answer for polygons which are almost complex or almost concave.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let vertices = [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)];
+    /// let square = Polygon::from_vertices((0.0, 0.0), vertices.into());
+    /// 
+    /// assert!(square.is_convex());
+    /// 
+    /// let concave_vertices = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 1.0), (0.0, 2.0)];
+    /// let concave_shape = Polygon::from_vertices((0.0, 0.0), concave_vertices.into());
+    /// 
+    /// assert!(!concave_shape.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool
+    {
+
+        if self.vertices.len() > 2
+        {
+
+            if let Some((first_x, first_y)) = self.vertices.first()
+            {
+
+                //We do not need to use position, as convexity is independent of position
+                let mut previous = (*first_x, *first_y);
+
+                //For each side, project the polygon onto its perpendicular axis
+                for (i, (x, y)) in self.vertices.iter().cycle().skip(1).take(self.vertices.len()).enumerate()
+                {
+
+                    let side = (*x - previous.0, *y - previous.1);
+                    let axis = (-side.1, side.0);
+
+                    let (min, max) = self.side_projection(axis, i + 1);
+
+                    //Measure the projections relative to the side itself, which need not pass through the origin
+                    let offset = (*x * axis.0) + (*y * axis.1);
+
+                    if min - offset < -f32::EPSILON && max - offset > f32::EPSILON
+                    {
+
+                        //There are points on both sides of a polygon edge, which must mean it is not convex
+                        return false;
+
+                    }
+
+                    previous = (*x, *y);
+
+                }
+
+            }
+
+        }
+
+        //If the polygon doesn't have enough vertices, then it is trivially convex
+        return true;
+
+    }
+
+    fn side_projection(&self, axis: (f32, f32), start: usize) -> (f32, f32)
+    {
+
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+
+        //Project all vertices not touching the given side onto the given axis
+        for (x, y) in self.vertices.iter().cycle().skip(start + 1).take(self.vertices.len() - 2)
+        {
+
+            //Dot product the current position vector to the axis of projection
+            let projection = (*x * axis.0) + (*y * axis.1);
+
+            min = f32::min(min, projection);
+            max = f32::max(max, projection);
+
+        }
+
+        return (min, max);
+
+    }
+
+    /// Adds a vertex to the given shape, setting it as the last vertex.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let vertices = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)];
+    /// let mut polygon = Polygon::from_vertices((0.0, 0.0), vertices.into()); //Triangle with vertices (0, 0), (2, 0), and (2, 2)
+    /// 
+    /// polygon.add((0.0, 2.0)) //Square with vertices (0, 0), (2, 0), (2, 2), and (0, 2)
+    /// ```
+    /// 
+    /// # Panics
+    /// 
+    /// Without the `alloc` feature, panics if the polygon already has `MAX_VERTICES` vertices.
+    pub fn add(&mut self, vertex: (f32, f32))
+    {
+
+        self.vertices.push(vertex);
+
+    }
+
+    /// Creates a polygon from the given vertices.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let vertices = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)];
+    /// let rectangle = Polygon::from_vertices((0.0, 0.0), vertices.into()); 
+    /// //Rectangle with vertices (0,0), (2,0), (2,1), and (0, 1)
+    /// ```
+    pub fn from_vertices(position: (f32, f32), vertices: Vertices) -> Polygon
+    {
+
+        return Polygon { position, vertices };
+
+    }
+
+    /// Attempts to create a convex polygon from the given vertices. It returns the poloygon if
+    /// it is convex, and `None` if it is concave. 
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let vertices = [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)];
+    /// let square = Polygon::convex_from_vertices((0.0, 0.0), vertices.into());
+    /// 
+    /// assert!(square.is_some());
+    /// 
+    /// let concave_vertices = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 1.0), (0.0, 2.0)];
+    /// let concave_shape = Polygon::convex_from_vertices((0.0, 0.0), concave_vertices.into());
+    /// 
+    /// assert!(concave_shape.is_none());
+    /// ```
+    pub fn convex_from_vertices(position: (f32, f32), vertices: Vertices) -> Option<Polygon>
+    {
+
+        let polygon = Polygon { position, vertices };
+
+        if polygon.is_convex()
+        {
+
+            return Some(polygon);
+
+        }
+
+        return None;
+
+    }
+
+    /// Returns the area enclosed by the polygon, which is positive when its vertices are in
+    /// counterclockwise order and negative when they are clockwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let square = Polygon::from_vertices((5.0, 5.0), [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into());
+    /// let clockwise = Polygon::from_vertices((5.0, 5.0), [(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)].into());
+    /// 
+    /// assert_eq!(square.signed_area(), 4.0);
+    /// assert_eq!(clockwise.signed_area(), -4.0);
+    /// ```
+    pub fn signed_area(&self) -> f32
+    {
+
+        return signed_area(&self.vertices);
+
+    }
+
+    /// Returns the absolute position of the centroid of the area enclosed by the polygon. For polygons
+    /// without area, such as points and line segments, it returns the average of the vertices instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let triangle = Polygon::from_vertices((1.0, 1.0), [(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)].into());
+    /// 
+    /// assert_eq!(triangle.centroid(), (2.0, 2.0));
+    /// ```
+    pub fn centroid(&self) -> (f32, f32)
+    {
+
+        if self.vertices.is_empty()
+        {
+
+            return self.position;
+
+        }
+
+        let area = self.signed_area();
+        let mut sum = (0.0, 0.0);
+
+        if area.abs() > f32::EPSILON
+        {
+
+            for i in 0..self.vertices.len()
+            {
+
+                let current = self.vertices[i];
+                let next = self.vertices[(i + 1) % self.vertices.len()];
+                let cross = (current.0 * next.1) - (next.0 * current.1);
+
+                sum = (sum.0 + ((current.0 + next.0) * cross), sum.1 + ((current.1 + next.1) * cross));
+
+            }
+
+            return (self.position.0 + (sum.0 / (6.0 * area)), self.position.1 + (sum.1 / (6.0 * area)));
+
+        }
+
+        for vertex in self.vertices.iter()
+        {
+
+            sum = (sum.0 + vertex.0, sum.1 + vertex.1);
+
+        }
+
+        let count = self.vertices.len() as f32;
+
+        return (self.position.0 + (sum.0 / count), self.position.1 + (sum.1 / count));
+
+    }
+
+    /// Reverses the order of the vertices if they are clockwise, so that they are always counterclockwise
+    /// when the Y-axis points up.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let mut triangle = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (0.0, 1.0), (1.0, 0.0)].into());
+    /// triangle.normalize_winding();
+    /// 
+    /// assert!(triangle.signed_area() > 0.0);
+    /// ```
+    pub fn normalize_winding(&mut self)
+    {
+
+        if self.signed_area() < 0.0
+        {
+
+            self.vertices.reverse();
+
+        }
+
+    }
+
+    /// Removes vertices which lie within the given distance of the previous vertex, or of the line
+    /// through their neighbours. Duplicate and collinear vertices add nothing to the shape of a
+    /// polygon, but each adds an axis to test during SAT collisions.
+    /// 
+    /// Removing vertices can leave fewer than three of them when the whole polygon lies within the
+    /// tolerance of a line segment or a point.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let mut square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (2.0, 2.0001), (0.0, 2.0)].into());
+    /// square.simplify(0.001);
+    /// 
+    /// assert_eq!(square.vertices.len(), 4);
+    /// ```
+    pub fn simplify(&mut self, tolerance: f32)
+    {
+
+        let squared = tolerance * tolerance;
+        let mut removed = true;
+
+        //Removing a vertex changes the neighbours of the ones around it, so repeat until nothing changes
+        while removed
+        {
+
+            removed = false;
+            let mut i = 0;
+
+            while i < self.vertices.len() && self.vertices.len() > 1
+            {
+
+                let count = self.vertices.len();
+
+                let previous = self.vertices[(i + count - 1) % count];
+                let current = self.vertices[i];
+                let next = self.vertices[(i + 1) % count];
+
+                let from_previous = (current.0 - previous.0, current.1 - previous.1);
+                let duplicate = (from_previous.0 * from_previous.0) + (from_previous.1 * from_previous.1) <= squared;
+
+                //The distance from the line through the neighbours is the cross product over the length of the line.
+                //Only vertices between their neighbours are removed, so the ends of a sliver stay in place
+                let line = (next.0 - previous.0, next.1 - previous.1);
+                let length = (line.0 * line.0) + (line.1 * line.1);
+                let cross = (line.0 * from_previous.1) - (line.1 * from_previous.0);
+                let along = (line.0 * from_previous.0) + (line.1 * from_previous.1);
+                let collinear = count > 2 && cross * cross <= squared * length && along >= 0.0 && along <= length;
+
+                if duplicate || collinear
+                {
+
+                    self.vertices.remove(i);
+                    removed = true;
+
+                }
+                else
+                {
+
+                    i += 1;
+
+                }
+
+            }
+
+        }
+
+    }
+
+    /// Moves the position of the polygon onto its centroid, adjusting the vertices so that the
+    /// polygon stays in place. Rotations then turn the polygon around its center.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sepax2d::prelude::*;
+    /// 
+    /// let mut square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into());
+    /// square.recenter();
+    /// 
+    /// assert_eq!(square.position, (1.0, 1.0));
+    /// assert_eq!(square.vertices[0], (-1.0, -1.0));
+    /// ```
+    pub fn recenter(&mut self)
+    {
+
+        let centroid = self.centroid();
+        let offset = (centroid.0 - self.position.0, centroid.1 - self.position.1);
+
+        for vertex in self.vertices.iter_mut()
+        {
+
+            *vertex = (vertex.0 - offset.0, vertex.1 - offset.1);
+
+        }
+
+        self.position = centroid;
+
+    }
+
+}
+
+/// Returns the area enclosed by the given vertices, which is positive when they are in counterclockwise
+/// order and negative when they are clockwise.
+pub fn signed_area(vertices: &[(f32, f32)]) -> f32
+{
+
+    let mut area = 0.0;
+
+    for i in 0..vertices.len()
+    {
+
+        let current = vertices[i];
+        let next = vertices[(i + 1) % vertices.len()];
+
+        area += (current.0 * next.1) - (next.0 * current.1);
+
+    }
+
+    return area / 2.0;
+
+}
+
+impl crate::Shape for Polygon
+{
+
+    fn position(&self) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+    fn set_position(&mut self, position: (f32, f32))
+    {
+
+        self.position = position;
+
+    }
+
+    fn num_axes(&self) -> usize
+    {
+
+        //We only perform collision check for polygons, not lines or points
+        if self.vertices.len() <= 1
+        {
+
+            return 0;
+
+        }
+
+        if self.vertices.len() == 2
+        {
+
+            return 1;
+
+        }
+
+        return self.vertices.len();
+
+    }
+
+
+    fn get_axis(&self, index: usize, _target: (f32, f32)) -> (f32, f32)
+    {
+
+        if self.vertices.len() <= 1 || index >= self.vertices.len()
+        {
+
+            return (0.0, 0.0);
+
+        }
+
+        let vertex = self.vertices[index];
+        let next = self.vertices[(index + 1) % self.vertices.len()];
+
+        let side = (next.0 - vertex.0, next.1 - vertex.1);
+        let axis = (-side.1, side.0);
+
+        return axis;
+
+    }
+
+    fn project(&self, axis: (f32, f32), _normalize: bool) -> (f32, f32)
+    {
+
+        return crate::project(self.position, axis, &self.vertices);
+
+    }
+
+    fn needs_closest(&self, _index: usize) -> bool
+    {
+
+        return false;
+
+    }
+
+    fn get_closest(&self, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return crate::closest(self.position, target, &self.vertices);
+
+    }
+
+    fn point(&self, index: usize) -> (f32, f32)
+    {
+
+        if index >= self.vertices.len()
+        {
+
+            return self.position;
+
+        }
+
+        let vertex = self.vertices[index];
+        return (self.position.0 + vertex.0, self.position.1 + vertex.1);
+
+    }
+
+}
+
+impl crate::Rotate for Polygon
+{
+
+    fn rotate(&mut self, angle: f32)
+    {
+
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
+
+        self.rotate_sincos(sin, cos);
+
+    }
+
+    fn rotate_sincos(&mut self, sin: f32, cos: f32)
+    {
+
+        for v in self.vertices.iter_mut()
+        {
+
+            *v = crate::rotate!(sin, cos, v);
+
+        }
+
+    }
+
+}
+
+impl crate::Distance for Polygon
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        return crate::polygon_boundary(self.position, &self.vertices, point);
+
+    }
+
+}
+
+#[cfg(test)]
+mod polygon_tests
+{
+
+    use super::*;
+    use crate::{float_equal, Shape, Distance};
+
+    #[test]
+    fn test_add_is_convex()
+    {
+
+        let mut square = Polygon::new((0.0, 0.0));
+        square.add((0.0, 0.0));
+        square.add((1.0, 0.0));
+        square.add((1.0, 1.0));
+        square.add((0.0, 1.0));
+
+        assert!(square.is_convex());
+
+        //Ensure that it works for polygons with more vertices than necessary
+        let mut triangle = Polygon::new((-32.0, 160.0));
+        triangle.add((-5.0, 0.0));
+        triangle.add((-2.0, -2.0));
+        triangle.add((5.0, 0.0));
+        triangle.add((0.0, 0.0));
+
+        assert!(triangle.is_convex());
+
+    }
+
+    #[test]
+    fn test_add_not_convex()
+    {
+
+        let mut four = Polygon::new((0.0, 0.0));
+        four.add((0.0, 0.0));
+        four.add((1.0, 0.0));
+        four.add((0.0, 1.0));
+        four.add((1.0, 1.0));
+
+        assert!(!four.is_convex());
+
+        let mut five = Polygon::new((-32.0, 160.0));
+        five.add((-5.0, 0.0));
+        five.add((-2.0, -2.0));
+        five.add((5.0, 0.0));
+        five.add((5.0, 10.0));
+        five.add((0.0, 0.0));
+
+        assert!(!five.is_convex());
+
+    }
+
+    #[test]
+    fn test_from_vertices_is_convex()
+    {
+
+        let vertices = [(8.0, 0.0), (12.0, 4.0), (6.0, 8.0), (0.0, 4.0), (0.0, 0.0)];
+        let pentagon = Polygon::from_vertices((3.0, 2.0), vertices.into());
+
+        assert!(pentagon.is_convex());
+
+    }
+
+    #[test]
+    fn test_is_convex_away_from_origin()
+    {
+
+        //None of the sides pass through the origin, so the vertices must be compared against the side itself
+        let vertices = [(-1.0, 2.0), (-2.0, 0.0), (-2.0, -2.0), (2.0, -2.0), (2.0, 0.0), (1.0, 2.0)];
+        let hexagon = Polygon::from_vertices((0.0, 0.0), vertices.into());
+
+        assert!(hexagon.is_convex());
+
+        let dented = Polygon::from_vertices((0.0, 0.0), [(10.0, 10.0), (14.0, 10.0), (12.0, 11.0), (14.0, 12.0), (10.0, 12.0)].into());
+
+        assert!(!dented.is_convex());
+
+    }
+
+    #[test]
+    fn test_from_vertices_not_convex()
+    {
+
+        let vertices = [(-4.0, 0.0), (-3.0, 2.0), (0.0, 1.0), (3.0, 2.0), (4.0, 0.0), (0.0, 0.0)];
+        let concave = Polygon::from_vertices((32.0, 4.56), vertices.into());
+
+        assert!(!concave.is_convex());
+
+    }
+
+    #[test]
+    fn test_convex_from_vertices_is_convex()
+    {
+
+        let vertices = [(1.0, 1.0), (1.0, 2.0), (0.0, 3.0), (-1.0, 2.0), (-1.0, 1.0), (0.0, 0.0)];
+        let hexagon = Polygon::convex_from_vertices((0.0, 0.0), vertices.into());
+
+        assert!(hexagon.is_some());
+
+    }
+
+    #[test]
+    fn test_convex_from_vertices_not_convex()
+    {
+
+        let vertices = [(1.0, 1.0), (-1.0, 1.0), (0.0, 3.0), (-1.0, 2.0), (2.0, 1.0), (0.0, 0.0)];
+        let concave = Polygon::convex_from_vertices((0.0, 0.0), vertices.into());
+
+        assert!(concave.is_none());
+
+    }
+
+    #[test]
+    fn test_num_axes()
+    {
+
+        let empty = Polygon::new((1.0, 2.0));
+        let point = Polygon::from_vertices((0.0, 3.0), [(2.0, 1.0)].into());
+        let line = Polygon::from_vertices((0.0, 0.0), [(0.0, 1.0), (0.0, 0.0)].into());
+
+        let vertices = [(1.0, 1.0), (1.0, 2.0), (0.0, 3.0), (-1.0, 2.0), (-1.0, 1.0), (0.0, 0.0)];
+        let hexagon = Polygon::from_vertices((0.0, 0.0), vertices.into());
+
+        assert_eq!(empty.num_axes(), 0);
+        assert_eq!(point.num_axes(), 0);
+        assert_eq!(line.num_axes(), 1);
+        assert_eq!(hexagon.num_axes(), 6);
+
+
+    }
+
+    #[test]
+    fn test_get_axis()
+    {
+
+        let vertices = [(1.0, 1.0), (1.0, 2.0), (0.0, 3.0), (-1.0, 2.0), (-1.0, 1.0), (0.0, 0.0)];
+        let hexagon = Polygon::from_vertices((0.0, 0.0), vertices.into());
+
+        let axis1 = hexagon.get_axis(1, (0.0, 0.0));
+        let axis3 = hexagon.get_axis(3, (110.0, 11.0));
+        let axis4 = hexagon.get_axis(5, (-1.0, 1.0 / 16.0));
+
+        assert!(float_equal(axis1.0, -1.0));
+        assert!(float_equal(axis1.1, -1.0));
+        assert!(float_equal(axis3.0, 1.0));
+        assert!(float_equal(axis3.1, 0.0));
+        assert!(float_equal(axis4.0, -1.0));
+        assert!(float_equal(axis4.1, 1.0));
+
+    }
+
+    #[test]
+    fn test_project()
+    {
+
+        let vertices = [(1.0, 1.0), (1.0, 2.0), (0.0, 3.0), (-1.0, 2.0), (-1.0, 1.0), (0.0, 0.0)];
+        let hexagon = Polygon::from_vertices((0.0, 0.0), vertices.into());
+
+        let axis = (1.0, 2.0);
+        let projection = hexagon.project(axis, false);
+
+        assert!(float_equal(projection.0, 0.0));
+        assert!(float_equal(projection.1, 6.0));
+
+    }
+
+    #[test]
+    fn test_needs_closest()
+    {
+
+        let square = Polygon::from_vertices((1.0, 2.0), [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].into());
+
+        assert!(!square.needs_closest(2));
+
+    }
+
+    #[test]
+    fn test_boundary()
+    {
+
+        let square = Polygon::from_vertices((1.0, 2.0), [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into());
+
+        let (point, normal, distance) = square.boundary((2.5, 3.0));
+
+        assert!(float_equal(point.0, 3.0));
+        assert!(float_equal(point.1, 3.0));
+        assert!(float_equal(normal.0, 1.0));
+        assert!(float_equal(normal.1, 0.0));
+        assert!(float_equal(distance, -0.5));
+
+        let (point, _, distance) = square.boundary((0.0, 2.0));
+
+        assert!(float_equal(point.0, 1.0));
+        assert!(float_equal(point.1, 2.0));
+        assert!(float_equal(distance, 1.0));
+
+    }
+
+    #[test]
+    fn test_signed_area()
+    {
+
+        let triangle = Polygon::from_vertices((3.0, -1.0), [(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)].into());
+        let mut clockwise = triangle.clone();
+        clockwise.vertices.reverse();
+
+        assert!(float_equal(triangle.signed_area(), 6.0));
+        assert!(float_equal(clockwise.signed_area(), -6.0));
+        assert!(float_equal(Polygon::from_vertices((0.0, 0.0), [(1.0, 1.0), (2.0, 2.0)].into()).signed_area(), 0.0));
+
+    }
+
+    #[test]
+    fn test_centroid()
+    {
+
+        let pentagon = Polygon::from_vertices((1.0, 1.0), [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 4.0), (0.0, 2.0)].into());
+        let mut clockwise = pentagon.clone();
+        clockwise.vertices.reverse();
+
+        //A 4x2 rectangle with centroid (2, 1) plus a triangle of area 4 with centroid (2, 8/3)
+        let expected = (1.0 + 2.0, 1.0 + ((8.0 * 1.0) + (4.0 * 8.0 / 3.0)) / 12.0);
+
+        assert!(float_equal(pentagon.centroid().0, expected.0) && float_equal(pentagon.centroid().1, expected.1));
+        assert!(float_equal(clockwise.centroid().0, expected.0) && float_equal(clockwise.centroid().1, expected.1));
+
+        let line = Polygon::from_vertices((0.0, 1.0), [(0.0, 0.0), (4.0, 2.0)].into());
+
+        assert!(float_equal(line.centroid().0, 2.0) && float_equal(line.centroid().1, 2.0));
+        assert_eq!(Polygon::new((5.0, 5.0)).centroid(), (5.0, 5.0));
+
+    }
+
+    #[test]
+    fn test_normalize_winding()
+    {
+
+        let mut clockwise = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)].into());
+        let mut counterclockwise = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].into());
+
+        clockwise.normalize_winding();
+        counterclockwise.normalize_winding();
+
+        assert_eq!(clockwise.vertices[..], [(2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)]);
+        assert_eq!(counterclockwise.vertices[..], [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+
+    }
+
+    #[test]
+    fn test_simplify()
+    {
+
+        //Duplicates, including the last vertex repeating the first, and collinear points on several sides
+        let mut outline = Polygon::from_vertices((0.0, 0.0), 
+        [
+            (0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (2.0, 0.0), (4.0, 0.00005),
+            (4.0, 2.0), (3.0, 3.0), (2.0, 4.0), (0.0, 4.0), (0.0, 1.0), (0.00005, 0.0)
+        ].into());
+
+        outline.simplify(0.0001);
+
+        assert_eq!(outline.vertices[..], [(4.0, 0.00005), (4.0, 2.0), (2.0, 4.0), (0.0, 4.0), (0.00005, 0.0)]);
+        assert_eq!(outline.num_axes(), 5);
+        assert!(outline.is_convex());
+
+        //Nothing changes when there is nothing to remove
+        let mut square = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].into());
+        square.simplify(0.0001);
+
+        assert_eq!(square.vertices.len(), 4);
+
+        //A polygon without width collapses to its end points
+        let mut sliver = Polygon::from_vertices((0.0, 0.0), [(0.0, 0.0), (1.0, 0.0), (3.0, 0.0), (2.0, 0.0)].into());
+        sliver.simplify(0.0001);
+
+        assert_eq!(sliver.vertices[..], [(0.0, 0.0), (3.0, 0.0)]);
+
+    }
+
+    #[test]
+    fn test_recenter()
+    {
+
+        let mut triangle = Polygon::from_vertices((1.0, 1.0), [(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)].into());
+        let before: Vec<(f32, f32)> = (0..3).map(|i| triangle.point(i)).collect();
+
+        triangle.recenter();
+
+        assert!(float_equal(triangle.position.0, 2.0) && float_equal(triangle.position.1, 2.0));
+
+        for (i, point) in before.iter().enumerate()
+        {
+
+            assert!(float_equal(triangle.point(i).0, point.0) && float_equal(triangle.point(i).1, point.1));
+
+        }
+
+        let centroid = triangle.centroid();
+
+        assert!(float_equal(centroid.0, 2.0) && float_equal(centroid.1, 2.0));
+
+    }
+
+}
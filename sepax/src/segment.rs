@@ -0,0 +1,264 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use crate::math;
+
+/// A line segment starting at position and ending at position plus vector, i.e. a shape
+/// with no area. Segments overlap the shapes they cross, touch from the side, or are contained
+/// in. Meeting another shape only at an end of the segment counts as touching, not overlapping.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// let laser = Segment::new((0.0, 0.0), (10.0, 0.0));
+/// let enemy = Circle::new((6.0, 0.5), 1.0);
+/// let wall = AABB::new((3.0, 2.0), 1.0, 4.0);
+///
+/// assert!(sat_overlap(&laser, &enemy));
+/// assert!(!sat_overlap(&laser, &wall));
+/// assert!(sat_overlap(&laser, &Segment::new((4.0, 0.0), (4.0, -3.0))));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment
+{
+
+    pub position: (f32, f32),
+    pub vector: (f32, f32)
+
+}
+
+impl Segment
+{
+
+    /// Create a new segment between the given start and end points.
+    pub fn new(start: (f32, f32), end: (f32, f32)) -> Segment
+    {
+
+        return Segment { position: start, vector: (end.0 - start.0, end.1 - start.1) };
+
+    }
+
+    /// Create a new segment starting at the given position and extending along the given vector.
+    pub fn from_vector(position: (f32, f32), vector: (f32, f32)) -> Segment
+    {
+
+        return Segment { position, vector };
+
+    }
+
+    /// The starting point of the segment, which is its position.
+    pub fn start(&self) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+    /// The ending point of the segment.
+    pub fn end(&self) -> (f32, f32)
+    {
+
+        return (self.position.0 + self.vector.0, self.position.1 + self.vector.1);
+
+    }
+
+    fn points(&self) -> [(f32, f32); 2]
+    {
+
+        return [(0.0, 0.0), self.vector];
+
+    }
+
+}
+
+impl crate::Shape for Segment
+{
+
+    fn position(&self) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+    fn set_position(&mut self, position: (f32, f32))
+    {
+
+        self.position = position;
+
+    }
+
+    fn num_axes(&self) -> usize
+    {
+
+        return 2;
+
+    }
+
+    //The normal separates shapes to either side, and the direction separates collinear shapes
+    fn get_axis(&self, index: usize, _target: (f32, f32)) -> (f32, f32)
+    {
+
+        return match index
+        {
+
+            0 => (-self.vector.1, self.vector.0),
+            _ => self.vector
+
+        };
+
+    }
+
+    fn project(&self, axis: (f32, f32), _normalize: bool) -> (f32, f32)
+    {
+
+        return crate::project(self.position, axis, &self.points());
+
+    }
+
+    fn needs_closest(&self, _index: usize) -> bool
+    {
+
+        return false;
+
+    }
+
+    fn get_closest(&self, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return crate::closest(self.position, target, &self.points());
+
+    }
+
+    fn point(&self, _index: usize) -> (f32, f32)
+    {
+
+        return self.position;
+
+    }
+
+}
+
+impl crate::Rotate for Segment
+{
+
+    fn rotate(&mut self, angle: f32)
+    {
+
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
+
+        self.rotate_sincos(sin, cos);
+
+    }
+
+    fn rotate_sincos(&mut self, sin: f32, cos: f32)
+    {
+
+        self.vector = crate::rotate!(sin, cos, self.vector);
+
+    }
+
+}
+
+impl crate::Distance for Segment
+{
+
+    /// The distance to a segment is never negative, since a segment has no inside.
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        let closest = crate::closest_on_segment(self.start(), self.end(), point);
+
+        let length = math::sqrt((self.vector.0 * self.vector.0) + (self.vector.1 * self.vector.1));
+        let fallback = if length > f32::EPSILON { (-self.vector.1 / length, self.vector.0 / length) } else { (1.0, 0.0) };
+
+        return crate::round_boundary(closest, 0.0, point, fallback);
+
+    }
+
+}
+
+#[cfg(test)]
+mod segment_tests
+{
+
+    use super::*;
+    use crate::{float_equal, Shape, Distance, Rotate};
+
+    #[test]
+    fn test_get_axis()
+    {
+
+        let segment = Segment::new((1.0, 1.0), (3.0, 2.0));
+
+        assert_eq!(segment.get_axis(0, (0.0, 0.0)), (-1.0, 2.0));
+        assert_eq!(segment.get_axis(1, (0.0, 0.0)), (2.0, 1.0));
+
+    }
+
+    #[test]
+    fn test_project()
+    {
+
+        let segment = Segment::new((1.0, 1.0), (3.0, 2.0));
+
+        let along = segment.project((1.0, 0.0), true);
+        assert!(float_equal(along.0, 1.0));
+        assert!(float_equal(along.1, 3.0));
+
+        let across = segment.project((-1.0, 2.0), false);
+        assert!(float_equal(across.0, 1.0));
+        assert!(float_equal(across.1, 1.0));
+
+    }
+
+    #[test]
+    fn test_get_closest()
+    {
+
+        let segment = Segment::new((1.0, 1.0), (3.0, 2.0));
+
+        assert_eq!(segment.get_closest((4.0, 4.0)), (3.0, 2.0));
+        assert_eq!(segment.get_closest((0.0, 1.0)), (1.0, 1.0));
+
+    }
+
+    #[test]
+    fn test_rotate()
+    {
+
+        let mut segment = Segment::new((1.0, 1.0), (3.0, 1.0));
+        segment.rotate(std::f32::consts::FRAC_PI_2);
+
+        assert_eq!(segment.start(), (1.0, 1.0));
+        assert!(float_equal(segment.end().0, 1.0));
+        assert!(float_equal(segment.end().1, 3.0));
+
+    }
+
+    #[test]
+    fn test_boundary()
+    {
+
+        let segment = Segment::new((0.0, 0.0), (4.0, 0.0));
+
+        let (closest, normal, distance) = segment.boundary((1.0, -2.0));
+        assert!(float_equal(closest.0, 1.0) && float_equal(closest.1, 0.0));
+        assert!(float_equal(normal.0, 0.0) && float_equal(normal.1, -1.0));
+        assert!(float_equal(distance, 2.0));
+
+        let (closest, _, distance) = segment.boundary((7.0, 4.0));
+        assert!(float_equal(closest.0, 4.0) && float_equal(closest.1, 0.0));
+        assert!(float_equal(distance, 5.0));
+
+        let (_, normal, distance) = segment.boundary((2.0, 0.0));
+        assert!(float_equal(normal.0, 0.0) && float_equal(normal.1, 1.0));
+        assert!(float_equal(distance, 0.0));
+
+    }
+
+}
@@ -0,0 +1,736 @@
+//! Tile maps, for level terrain made of a grid of square tiles. Maps are read from text,
+//! one character per tile, and only the tiles covered by a query are visited, so queries stay
+//! cheap however large the level is.
+//!
+//! Tiles are either solid or one-way. A one-way tile only stops a shape landing on it from
+//! above, which makes it a platform that can be jumped through from below.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::tilemap::TileMap;
+//!
+//! let map = TileMap::from_text((0.0, 0.0), 1.0, "...\n.--\n#.#").unwrap();
+//!
+//! //Falling onto the platform lands on it, while jumping up passes through
+//! let hit = map.sweep(&AABB::new((1.25, 2.5), 0.5, 1.0), (0.0, -2.0)).unwrap();
+//! assert_eq!(hit.tile, (1, 1));
+//! assert!(map.sweep(&AABB::new((1.25, -0.5), 0.5, 0.25), (0.0, 2.0)).is_none());
+//! ```
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, ser::SerializeStruct, Serializer, Deserialize, Deserializer};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::aabb::AABB;
+use crate::math;
+
+/// The contents of a single cell of a `TileMap`.
+///
+/// One-way tiles only block shapes which are falling onto them from above, so they can be
+/// jumped through from below or passed sideways.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile
+{
+
+    Empty,
+    Solid,
+    OneWay
+
+}
+
+impl Tile
+{
+
+    /// The tile described by the given character of a text layout: `#` is solid, `-` is one-way,
+    /// and `.` or a space is empty.
+    pub fn from_char(character: char) -> Option<Tile>
+    {
+
+        return match character
+        {
+
+            '.' | ' ' => Some(Tile::Empty),
+            '#' => Some(Tile::Solid),
+            '-' => Some(Tile::OneWay),
+            _ => None
+
+        };
+
+    }
+
+    /// The character used for this tile in a text layout.
+    pub fn to_char(self) -> char
+    {
+
+        return match self
+        {
+
+            Tile::Empty => '.',
+            Tile::Solid => '#',
+            Tile::OneWay => '-'
+
+        };
+
+    }
+
+}
+
+/// The first tile hit by a moving AABB, as found by `TileMap::sweep`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TileHit
+{
+
+    /// The fraction of the movement, between 0 and 1, completed before touching the tile.
+    pub time: f32,
+    /// The normal of the tile's face which was hit, pointing back toward the moving AABB.
+    pub normal: (f32, f32),
+    /// The column and row of the tile which was hit.
+    pub tile: (usize, usize)
+
+}
+
+/// A grid of square tiles, used as a collision layer for level terrain.
+///
+/// The grid's position is the corner of tile (0, 0) with the smallest coordinates, columns
+/// increase along the x axis and rows increase along the y axis. Tiles outside of the grid
+/// are empty.
+///
+/// Maps can be written as text, one line per row, with the first line being the top row. With
+/// the `serde` feature enabled, a map is (de)serialized in the same layout, e.g. in RON:
+///
+/// ```text
+/// (
+///     position: (0.0, 0.0),
+///     tile_size: 16.0,
+///     rows: [
+///         "#....#",
+///         "#.--.#",
+///         "######",
+///     ],
+/// )
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+/// use sepax2d::tilemap::{TileMap, Tile};
+///
+/// let map = TileMap::from_text((0.0, 0.0), 1.0, "#..\n###").unwrap();
+///
+/// assert_eq!(map.get(0, 1), Tile::Solid);
+/// assert_eq!(map.get(1, 1), Tile::Empty);
+///
+/// let player = AABB::new((1.25, 0.5), 0.5, 1.0);
+/// assert!(map.overlaps(&player));
+///
+/// let hit = map.sweep(&AABB::new((1.25, 1.5), 0.5, 1.0), (0.0, -1.0)).unwrap();
+/// assert!((hit.time - 0.5).abs() < 0.0001);
+/// assert_eq!(hit.normal, (0.0, 1.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TileMap
+{
+
+    pub position: (f32, f32),
+    pub tile_size: f32,
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>
+
+}
+
+impl TileMap
+{
+
+    /// Create an empty map of the given number of columns and rows.
+    pub fn new(position: (f32, f32), tile_size: f32, width: usize, height: usize) -> TileMap
+    {
+
+        return TileMap { position, tile_size, width, height, tiles: vec![Tile::Empty; width * height] };
+
+    }
+
+    /// Create a map from a text layout, with one line per row and the first line being the top row.
+    /// Shorter lines are padded with empty tiles. Returns None if the layout contains a character
+    /// other than those described in `Tile::from_char`.
+    pub fn from_text(position: (f32, f32), tile_size: f32, text: &str) -> Option<TileMap>
+    {
+
+        let lines: Vec<&str> = text.lines().map(|line| line.trim_end_matches('\r')).collect();
+
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let height = lines.len();
+
+        let mut map = TileMap::new(position, tile_size, width, height);
+
+        for (i, line) in lines.iter().enumerate()
+        {
+
+            let y = height - 1 - i;
+
+            for (x, character) in line.chars().enumerate()
+            {
+
+                map.set(x, y, Tile::from_char(character)?);
+
+            }
+
+        }
+
+        return Some(map);
+
+    }
+
+    /// The text layout of this map, in the format accepted by `from_text`.
+    pub fn to_text(&self) -> String
+    {
+
+        return self.rows().join("\n");
+
+    }
+
+    fn rows(&self) -> Vec<String>
+    {
+
+        return (0..self.height).rev().map(|y| (0..self.width).map(|x| self.get(x, y).to_char()).collect()).collect();
+
+    }
+
+    /// The number of columns in the map.
+    pub fn width(&self) -> usize
+    {
+
+        return self.width;
+
+    }
+
+    /// The number of rows in the map.
+    pub fn height(&self) -> usize
+    {
+
+        return self.height;
+
+    }
+
+    /// The tile at the given column and row, which is empty outside of the map.
+    pub fn get(&self, x: usize, y: usize) -> Tile
+    {
+
+        if x >= self.width || y >= self.height
+        {
+
+            return Tile::Empty;
+
+        }
+
+        return self.tiles[(y * self.width) + x];
+
+    }
+
+    /// Set the tile at the given column and row. Tiles outside of the map are ignored.
+    pub fn set(&mut self, x: usize, y: usize, tile: Tile)
+    {
+
+        if x < self.width && y < self.height
+        {
+
+            self.tiles[(y * self.width) + x] = tile;
+
+        }
+
+    }
+
+    /// The column and row of the tile containing the given point, if it is inside the map.
+    pub fn tile_at(&self, point: (f32, f32)) -> Option<(usize, usize)>
+    {
+
+        let x = math::floor((point.0 - self.position.0) / self.tile_size);
+        let y = math::floor((point.1 - self.position.1) / self.tile_size);
+
+        if x < 0.0 || y < 0.0 || x >= self.width as f32 || y >= self.height as f32
+        {
+
+            return None;
+
+        }
+
+        return Some((x as usize, y as usize));
+
+    }
+
+    /// The bounds of the tile at the given column and row.
+    pub fn tile_bounds(&self, x: usize, y: usize) -> AABB
+    {
+
+        let position = (self.position.0 + (x as f32 * self.tile_size), self.position.1 + (y as f32 * self.tile_size));
+
+        return AABB::new(position, self.tile_size, self.tile_size);
+
+    }
+
+    /// The non-empty tiles whose interiors overlap the given AABB, as (column, row, tile).
+    /// Only the tiles covered by the AABB are visited.
+    pub fn tiles_overlapping<'a>(&'a self, aabb: &AABB) -> impl Iterator<Item = (usize, usize, Tile)> + 'a
+    {
+
+        let (columns, rows) = self.range(aabb.position, (aabb.position.0 + aabb.width, aabb.position.1 + aabb.height));
+
+        return rows.flat_map(move |y| columns.clone().map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, self.get(x, y)))
+            .filter(|(_, _, tile)| *tile != Tile::Empty);
+
+    }
+
+    /// Checks if the given AABB overlaps any solid tile. One-way tiles are ignored, since they
+    /// only block movement.
+    pub fn overlaps(&self, aabb: &AABB) -> bool
+    {
+
+        return self.tiles_overlapping(aabb).any(|(_, _, tile)| tile == Tile::Solid);
+
+    }
+
+    /// Find the first tile hit by the given AABB when it moves by the given velocity. Tiles which
+    /// the AABB already overlaps are ignored, and one-way tiles are only hit from above while
+    /// moving down.
+    ///
+    /// Moving the AABB by `velocity * hit.time` places it touching the tile which was hit.
+    pub fn sweep(&self, aabb: &AABB, velocity: (f32, f32)) -> Option<TileHit>
+    {
+
+        let start = aabb.position;
+        let end = (start.0 + velocity.0, start.1 + velocity.1);
+
+        let min = (f32::min(start.0, end.0), f32::min(start.1, end.1));
+        let max = (f32::max(start.0, end.0) + aabb.width, f32::max(start.1, end.1) + aabb.height);
+
+        let (columns, rows) = self.range(min, max);
+
+        let mut closest: Option<TileHit> = None;
+
+        for y in rows
+        {
+
+            for x in columns.clone()
+            {
+
+                let tile = self.get(x, y);
+
+                if tile == Tile::Empty
+                {
+
+                    continue;
+
+                }
+
+                let bounds = self.tile_bounds(x, y);
+
+                if let Some((time, normal)) = sweep_aabb(aabb, velocity, &bounds)
+                {
+
+                    //One-way tiles only stop an AABB landing on their top face
+                    if tile == Tile::OneWay && normal != (0.0, 1.0)
+                    {
+
+                        continue;
+
+                    }
+
+                    if closest.is_none_or(|hit| time < hit.time)
+                    {
+
+                        closest = Some(TileHit { time, normal, tile: (x, y) });
+
+                    }
+
+                }
+
+            }
+
+        }
+
+        return closest;
+
+    }
+
+    /// Merge the solid tiles into as few boxes as possible, greedily growing each box first
+    /// along the row and then upward. Use this to turn the map into colliders for other systems,
+    /// without seams between neighbouring tiles.
+    pub fn merged_boxes(&self) -> Vec<AABB>
+    {
+
+        let mut used = vec![false; self.tiles.len()];
+        let mut boxes = Vec::new();
+
+        for y in 0..self.height
+        {
+
+            for x in 0..self.width
+            {
+
+                if used[(y * self.width) + x] || self.get(x, y) != Tile::Solid
+                {
+
+                    continue;
+
+                }
+
+                let free = |x: usize, y: usize| !used[(y * self.width) + x] && self.get(x, y) == Tile::Solid;
+
+                let mut width = 1;
+                while x + width < self.width && free(x + width, y)
+                {
+
+                    width += 1;
+
+                }
+
+                let mut height = 1;
+                while y + height < self.height && (x..x + width).all(|column| free(column, y + height))
+                {
+
+                    height += 1;
+
+                }
+
+                for row in y..y + height
+                {
+
+                    for column in x..x + width
+                    {
+
+                        used[(row * self.width) + column] = true;
+
+                    }
+
+                }
+
+                let position = self.tile_bounds(x, y).position;
+                boxes.push(AABB::new(position, width as f32 * self.tile_size, height as f32 * self.tile_size));
+
+            }
+
+        }
+
+        return boxes;
+
+    }
+
+    //The columns and rows of the tiles whose interiors overlap the given region, clamped to the map
+    fn range(&self, min: (f32, f32), max: (f32, f32)) -> (core::ops::Range<usize>, core::ops::Range<usize>)
+    {
+
+        let first = |value: f32, origin: f32| f32::max(math::floor((value - origin) / self.tile_size), 0.0) as usize;
+        let last = |value: f32, origin: f32| f32::max(math::ceil((value - origin) / self.tile_size), 0.0) as usize;
+
+        let columns = first(min.0, self.position.0)..usize::min(last(max.0, self.position.0), self.width);
+        let rows = first(min.1, self.position.1)..usize::min(last(max.1, self.position.1), self.height);
+
+        return (columns, rows);
+
+    }
+
+}
+
+//Time of impact and normal of a moving AABB against a static one, ignoring boxes already overlapping
+fn sweep_aabb(moving: &AABB, velocity: (f32, f32), target: &AABB) -> Option<(f32, (f32, f32))>
+{
+
+    let mut entry = f32::NEG_INFINITY;
+    let mut exit = f32::INFINITY;
+    let mut normal = (0.0, 0.0);
+
+    let axes =
+    [
+
+        (moving.position.0, moving.width, velocity.0, target.position.0, target.width, (1.0, 0.0)),
+        (moving.position.1, moving.height, velocity.1, target.position.1, target.height, (0.0, 1.0))
+
+    ];
+
+    for (position, size, speed, target_position, target_size, axis) in axes
+    {
+
+        let gap_before = target_position - (position + size);
+        let gap_after = position - (target_position + target_size);
+
+        if speed.abs() < f32::EPSILON
+        {
+
+            //Not moving along this axis, so the intervals must already overlap
+            if gap_before >= 0.0 || gap_after >= 0.0
+            {
+
+                return None;
+
+            }
+
+            continue;
+
+        }
+
+        let (near, far, face) = if speed > 0.0
+        {
+
+            (gap_before / speed, (target_position + target_size - position) / speed, (-axis.0, -axis.1))
+
+        }
+        else
+        {
+
+            (gap_after / -speed, (position + size - target_position) / -speed, axis)
+
+        };
+
+        if near > entry
+        {
+
+            entry = near;
+            normal = face;
+
+        }
+
+        exit = f32::min(exit, far);
+
+    }
+
+    if !(0.0..=1.0).contains(&entry) || entry >= exit
+    {
+
+        return None;
+
+    }
+
+    return Some((entry, normal));
+
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "TileMap")]
+struct Layout
+{
+
+    position: (f32, f32),
+    tile_size: f32,
+    rows: Vec<String>
+
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for TileMap
+{
+
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer
+    {
+
+        let mut state = serializer.serialize_struct("TileMap", 3)?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field("tile_size", &self.tile_size)?;
+        state.serialize_field("rows", &self.rows())?;
+        state.end()
+
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl <'de> Deserialize<'de> for TileMap
+{
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de>
+    {
+
+        let raw = <Layout>::deserialize(deserializer)?;
+
+        return TileMap::from_text(raw.position, raw.tile_size, &raw.rows.join("\n"))
+            .ok_or_else(|| serde::de::Error::custom("unknown tile character, expected one of '#', '-', '.' or ' '"));
+
+    }
+
+}
+
+#[cfg(test)]
+mod tilemap_tests
+{
+
+    use super::*;
+    use crate::float_equal;
+
+    const LEVEL: &str = "\
+#......#
+#..--..#
+#......#
+########";
+
+    #[test]
+    fn test_from_text()
+    {
+
+        let map = TileMap::from_text((0.0, 0.0), 2.0, LEVEL).unwrap();
+
+        assert_eq!(map.width(), 8);
+        assert_eq!(map.height(), 4);
+
+        assert_eq!(map.get(0, 3), Tile::Solid);
+        assert_eq!(map.get(1, 3), Tile::Empty);
+        assert_eq!(map.get(3, 2), Tile::OneWay);
+        assert_eq!(map.get(4, 0), Tile::Solid);
+        assert_eq!(map.get(8, 0), Tile::Empty);
+
+        assert_eq!(map.to_text(), LEVEL);
+
+        assert!(TileMap::from_text((0.0, 0.0), 1.0, "#.x").is_none());
+
+        let ragged = TileMap::from_text((0.0, 0.0), 1.0, "#\n###").unwrap();
+        assert_eq!(ragged.width(), 3);
+        assert_eq!(ragged.get(2, 1), Tile::Empty);
+
+    }
+
+    #[test]
+    fn test_tile_at()
+    {
+
+        let map = TileMap::from_text((-4.0, 2.0), 2.0, LEVEL).unwrap();
+
+        assert_eq!(map.tile_at((-3.0, 2.5)), Some((0, 0)));
+        assert_eq!(map.tile_at((1.0, 7.0)), Some((2, 2)));
+        assert_eq!(map.tile_at((-5.0, 3.0)), None);
+        assert_eq!(map.tile_at((0.0, 10.0)), None);
+
+        let bounds = map.tile_bounds(2, 2);
+        assert!(float_equal(bounds.position.0, 0.0));
+        assert!(float_equal(bounds.position.1, 6.0));
+
+    }
+
+    #[test]
+    fn test_overlaps()
+    {
+
+        let map = TileMap::from_text((0.0, 0.0), 2.0, LEVEL).unwrap();
+
+        assert!(map.overlaps(&AABB::new((4.0, 1.0), 1.0, 2.0)));
+        assert!(map.overlaps(&AABB::new((1.5, 4.0), 1.0, 1.0)));
+        assert!(!map.overlaps(&AABB::new((4.0, 2.0), 1.0, 2.0)));
+
+        //One-way tiles do not count as overlapping
+        assert!(!map.overlaps(&AABB::new((6.5, 4.5), 2.0, 1.0)));
+        assert_eq!(map.tiles_overlapping(&AABB::new((6.5, 4.5), 2.0, 1.0)).count(), 2);
+
+        assert!(!map.overlaps(&AABB::new((-10.0, -10.0), 5.0, 5.0)));
+
+    }
+
+    #[test]
+    fn test_sweep()
+    {
+
+        let map = TileMap::from_text((0.0, 0.0), 2.0, LEVEL).unwrap();
+        let player = AABB::new((4.0, 2.5), 1.0, 1.0);
+
+        let floor = map.sweep(&player, (0.0, -1.0)).unwrap();
+        assert!(float_equal(floor.time, 0.5));
+        assert_eq!(floor.normal, (0.0, 1.0));
+        assert_eq!(floor.tile, (2, 0));
+
+        let wall = map.sweep(&player, (12.0, 0.0)).unwrap();
+        assert!(float_equal(wall.time, 9.0 / 12.0));
+        assert_eq!(wall.normal, (-1.0, 0.0));
+        assert_eq!(wall.tile, (7, 1));
+
+        assert!(map.sweep(&player, (1.0, 1.0)).is_none());
+
+    }
+
+    #[test]
+    fn test_one_way()
+    {
+
+        let map = TileMap::from_text((0.0, 0.0), 2.0, LEVEL).unwrap();
+
+        //Jumping up through the platform
+        assert!(map.sweep(&AABB::new((6.5, 2.5), 1.0, 1.0), (0.0, 4.0)).is_none());
+
+        //Walking sideways into it
+        assert!(map.sweep(&AABB::new((3.0, 4.5), 1.0, 1.0), (4.0, 0.0)).is_none());
+
+        //Landing on top of it
+        let hit = map.sweep(&AABB::new((6.5, 7.0), 1.0, 1.0), (0.0, -2.0)).unwrap();
+        assert!(float_equal(hit.time, 0.5));
+        assert_eq!(hit.normal, (0.0, 1.0));
+        assert_eq!(hit.tile, (3, 2));
+
+    }
+
+    #[test]
+    fn test_merged_boxes()
+    {
+
+        let map = TileMap::from_text((0.0, 0.0), 2.0, LEVEL).unwrap();
+        let boxes = map.merged_boxes();
+
+        assert_eq!(boxes.len(), 3);
+
+        assert!(float_equal(boxes[0].width, 16.0));
+        assert!(float_equal(boxes[0].height, 2.0));
+
+        let total: f32 = boxes.iter().map(|aabb| aabb.width * aabb.height).sum();
+        assert!(float_equal(total, 56.0));
+
+        for y in 0..map.height()
+        {
+
+            for x in 0..map.width()
+            {
+
+                let bounds = map.tile_bounds(x, y);
+                let center = (bounds.position.0 + 1.0, bounds.position.1 + 1.0);
+                let covered = boxes.iter().any(|aabb| crate::contains_point(aabb, center));
+
+                assert_eq!(covered, map.get(x, y) == Tile::Solid);
+
+            }
+
+        }
+
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ron_layout()
+    {
+
+        let source = "(
+            position: (0.0, 0.0),
+            tile_size: 16.0,
+            rows: [
+                \"#....#\",
+                \"#.--.#\",
+                \"######\",
+            ],
+        )";
+
+        let map: TileMap = ron::from_str(source).unwrap();
+
+        assert_eq!(map.width(), 6);
+        assert_eq!(map.height(), 3);
+        assert_eq!(map.get(2, 1), Tile::OneWay);
+        assert!(float_equal(map.tile_size, 16.0));
+
+        let serialized = ron::to_string(&map).unwrap();
+        let deserialized: TileMap = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.to_text(), map.to_text());
+
+        assert!(ron::from_str::<TileMap>("(position: (0.0, 0.0), tile_size: 1.0, rows: [\"#?\"])").is_err());
+
+    }
+
+}
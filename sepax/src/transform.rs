@@ -0,0 +1,613 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+use crate::{Shape, Distance};
+use crate::polygon::Polygon;
+use crate::circle::Circle;
+use crate::aabb::AABB;
+use crate::capsule::Capsule;
+use crate::parallelogram::Parallelogram;
+use crate::point::Point;
+use crate::segment::Segment;
+use crate::collider::Collider;
+use crate::math;
+
+/// A 2D transform made of a uniform scale, followed by a counterclockwise rotation, followed
+/// by a translation.
+///
+/// The scale is uniform so that transformed circles and capsules remain circles and capsules.
+/// It is expected to be positive.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::prelude::*;
+///
+/// //A hitbox attached to a boss, described relative to the boss
+/// let arm = Capsule::new((4.0, 0.0), (2.0, 0.0), 1.0);
+/// let boss = Transform2D::new((10.0, 5.0), std::f32::consts::FRAC_PI_2, 2.0);
+///
+/// //A new shape in world space
+/// let world = arm.transformed(&boss);
+/// assert!((world.position.0 - 10.0).abs() < 0.0001 && (world.position.1 - 13.0).abs() < 0.0001);
+///
+/// //Or query the original shape in world space without creating a new one
+/// let bullet = Circle::new((11.0, 18.0), 0.5);
+/// assert!(sat_overlap(&Transformed::new(&arm, boss), &bullet));
+/// assert!(sat_overlap(&world, &bullet));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Transform2D
+{
+
+    pub translation: (f32, f32),
+    pub rotation: f32,
+    pub scale: f32
+
+}
+
+impl Transform2D
+{
+
+    /// The transform which leaves every point where it is.
+    pub const IDENTITY: Transform2D = Transform2D { translation: (0.0, 0.0), rotation: 0.0, scale: 1.0 };
+
+    /// Create a new transform with the given translation, rotation angle, and scale.
+    pub fn new(translation: (f32, f32), rotation: f32, scale: f32) -> Transform2D
+    {
+
+        return Transform2D { translation, rotation, scale };
+
+    }
+
+    /// Create a transform which only translates.
+    pub fn from_translation(translation: (f32, f32)) -> Transform2D
+    {
+
+        return Transform2D { translation, ..Transform2D::IDENTITY };
+
+    }
+
+    /// Create a transform which only rotates around the origin.
+    pub fn from_rotation(rotation: f32) -> Transform2D
+    {
+
+        return Transform2D { rotation, ..Transform2D::IDENTITY };
+
+    }
+
+    /// Create a transform which rotates by the given angle around the given pivot.
+    pub fn rotation_about(pivot: (f32, f32), rotation: f32) -> Transform2D
+    {
+
+        let (sin, cos) = math::sin_cos(rotation);
+        let rotated = crate::rotate!(sin, cos, pivot);
+
+        return Transform2D::new((pivot.0 - rotated.0, pivot.1 - rotated.1), rotation, 1.0);
+
+    }
+
+    /// Apply the transform to a point.
+    pub fn apply(&self, point: (f32, f32)) -> (f32, f32)
+    {
+
+        let vector = self.apply_vector(point);
+
+        return (vector.0 + self.translation.0, vector.1 + self.translation.1);
+
+    }
+
+    /// Apply the transform to a vector, i.e. scale and rotate it without translating it.
+    pub fn apply_vector(&self, vector: (f32, f32)) -> (f32, f32)
+    {
+
+        let (sin, cos) = math::sin_cos(self.rotation);
+        let rotated = crate::rotate!(sin, cos, vector);
+
+        return (rotated.0 * self.scale, rotated.1 * self.scale);
+
+    }
+
+    /// The transform which undoes this one.
+    pub fn inverse(&self) -> Transform2D
+    {
+
+        let scale = 1.0 / self.scale;
+        let (sin, cos) = math::sin_cos(-self.rotation);
+        let rotated = crate::rotate!(sin, cos, self.translation);
+
+        return Transform2D::new((-rotated.0 * scale, -rotated.1 * scale), -self.rotation, scale);
+
+    }
+
+    /// The transform which applies this transform first, and then the given one. Use it to
+    /// place a shape relative to a parent, as in `local.then(&parent)`.
+    pub fn then(&self, next: &Transform2D) -> Transform2D
+    {
+
+        return Transform2D::new(next.apply(self.translation), self.rotation + next.rotation, self.scale * next.scale);
+
+    }
+
+}
+
+impl Default for Transform2D
+{
+
+    fn default() -> Self
+    {
+
+        return Transform2D::IDENTITY;
+
+    }
+
+}
+
+/// A trait for shapes which can produce a transformed copy of themselves. Applicable to all
+/// shapes; an AABB becomes a parallelogram, since it may no longer be axis-aligned.
+pub trait Transformable
+{
+
+    type Output: Shape;
+
+    /// A copy of the shape with the transform applied to it.
+    fn transformed(&self, transform: &Transform2D) -> Self::Output;
+
+}
+
+impl Transformable for Polygon
+{
+
+    type Output = Polygon;
+
+    fn transformed(&self, transform: &Transform2D) -> Polygon
+    {
+
+        let vertices = self.vertices.iter().map(|vertex| transform.apply_vector(*vertex)).collect();
+
+        return Polygon::from_vertices(transform.apply(self.position), vertices);
+
+    }
+
+}
+
+impl Transformable for Circle
+{
+
+    type Output = Circle;
+
+    fn transformed(&self, transform: &Transform2D) -> Circle
+    {
+
+        return Circle::new(transform.apply(self.position), self.radius * transform.scale);
+
+    }
+
+}
+
+impl Transformable for AABB
+{
+
+    type Output = Parallelogram;
+
+    fn transformed(&self, transform: &Transform2D) -> Parallelogram
+    {
+
+        return Parallelogram::rectangle(self.position, self.width, self.height).transformed(transform);
+
+    }
+
+}
+
+impl Transformable for Capsule
+{
+
+    type Output = Capsule;
+
+    fn transformed(&self, transform: &Transform2D) -> Capsule
+    {
+
+        return Capsule::new(transform.apply(self.position), transform.apply_vector(self.arm()), self.radius * transform.scale);
+
+    }
+
+}
+
+impl Transformable for Parallelogram
+{
+
+    type Output = Parallelogram;
+
+    fn transformed(&self, transform: &Transform2D) -> Parallelogram
+    {
+
+        return Parallelogram::new(transform.apply(self.position), transform.apply_vector(self.u), transform.apply_vector(self.v));
+
+    }
+
+}
+
+impl Transformable for Point
+{
+
+    type Output = Point;
+
+    fn transformed(&self, transform: &Transform2D) -> Point
+    {
+
+        return Point::new(transform.apply(self.position));
+
+    }
+
+}
+
+impl Transformable for Segment
+{
+
+    type Output = Segment;
+
+    fn transformed(&self, transform: &Transform2D) -> Segment
+    {
+
+        return Segment::from_vector(transform.apply(self.position), transform.apply_vector(self.vector));
+
+    }
+
+}
+
+impl Transformable for Collider
+{
+
+    type Output = Collider;
+
+    /// Transformed AABBs stay AABBs when the transform does not rotate them.
+    fn transformed(&self, transform: &Transform2D) -> Collider
+    {
+
+        return match self
+        {
+
+            Collider::Polygon(polygon) => Collider::Polygon(polygon.transformed(transform)),
+            Collider::Circle(circle) => Collider::Circle(circle.transformed(transform)),
+            Collider::AABB(aabb) if transform.rotation == 0.0 =>
+            {
+
+                Collider::AABB(AABB::new(transform.apply(aabb.position), aabb.width * transform.scale, aabb.height * transform.scale))
+
+            },
+            Collider::AABB(aabb) => Collider::Parallelogram(aabb.transformed(transform)),
+            Collider::Capsule(capsule) => Collider::Capsule(capsule.transformed(transform)),
+            Collider::Parallelogram(gram) => Collider::Parallelogram(gram.transformed(transform)),
+            Collider::Point(point) => Collider::Point(point.transformed(transform)),
+            Collider::Segment(segment) => Collider::Segment(segment.transformed(transform))
+
+        };
+
+    }
+
+}
+
+/// A lazily transformed view of a shape, which can be used anywhere a shape can without
+/// copying or mutating the original. Every query maps its arguments into the shape's own
+/// space and maps the results back out.
+///
+/// Setting the position of the view moves its translation, not the underlying shape.
+#[derive(Clone, Copy, Debug)]
+pub struct Transformed<'a, S: Shape + ?Sized>
+{
+
+    pub shape: &'a S,
+    transform: Transform2D,
+    inverse: Transform2D,
+    sin: f32,
+    cos: f32
+
+}
+
+impl <'a, S: Shape + ?Sized> Transformed<'a, S>
+{
+
+    /// Create a view of the given shape with the given transform applied to it.
+    pub fn new(shape: &'a S, transform: Transform2D) -> Transformed<'a, S>
+    {
+
+        let (sin, cos) = math::sin_cos(transform.rotation);
+
+        return Transformed { shape, transform, inverse: transform.inverse(), sin, cos };
+
+    }
+
+    /// The transform applied to the shape.
+    pub fn transform(&self) -> Transform2D
+    {
+
+        return self.transform;
+
+    }
+
+}
+
+impl <'a, S: Shape + ?Sized> Shape for Transformed<'a, S>
+{
+
+    fn position(&self) -> (f32, f32)
+    {
+
+        return self.transform.apply(self.shape.position());
+
+    }
+
+    fn set_position(&mut self, position: (f32, f32))
+    {
+
+        let current = self.position();
+        let translation = self.transform.translation;
+
+        self.transform.translation = (translation.0 + position.0 - current.0, translation.1 + position.1 - current.1);
+        self.inverse = self.transform.inverse();
+
+    }
+
+    fn num_axes(&self) -> usize
+    {
+
+        return self.shape.num_axes();
+
+    }
+
+    fn get_axis(&self, index: usize, target: (f32, f32)) -> (f32, f32)
+    {
+
+        //The scale does not change the direction of an axis
+        let axis = self.shape.get_axis(index, self.inverse.apply(target));
+
+        return crate::rotate!(self.sin, self.cos, axis);
+
+    }
+
+    fn project(&self, axis: (f32, f32), normalize: bool) -> (f32, f32)
+    {
+
+        let local = crate::rotate!(-self.sin, self.cos, axis);
+        let (min, max) = self.shape.project(local, normalize);
+
+        let offset = (self.transform.translation.0 * axis.0) + (self.transform.translation.1 * axis.1);
+
+        return (offset + (min * self.transform.scale), offset + (max * self.transform.scale));
+
+    }
+
+    fn needs_closest(&self, index: usize) -> bool
+    {
+
+        return self.shape.needs_closest(index);
+
+    }
+
+    fn get_closest(&self, target: (f32, f32)) -> (f32, f32)
+    {
+
+        return self.transform.apply(self.shape.get_closest(self.inverse.apply(target)));
+
+    }
+
+    fn point(&self, index: usize) -> (f32, f32)
+    {
+
+        return self.transform.apply(self.shape.point(index));
+
+    }
+
+}
+
+impl <'a, S: Shape + Distance + ?Sized> Distance for Transformed<'a, S>
+{
+
+    fn boundary(&self, point: (f32, f32)) -> ((f32, f32), (f32, f32), f32)
+    {
+
+        let (closest, normal, distance) = self.shape.boundary(self.inverse.apply(point));
+
+        return (self.transform.apply(closest), crate::rotate!(self.sin, self.cos, normal), distance * self.transform.scale);
+
+    }
+
+}
+
+#[cfg(test)]
+mod transform_tests
+{
+
+    use super::*;
+    use crate::{float_equal, sat_overlap, sat_collision, contains_point, signed_distance, Rotate};
+
+    fn point_equal(left: (f32, f32), right: (f32, f32)) -> bool
+    {
+
+        return float_equal(left.0, right.0) && float_equal(left.1, right.1);
+
+    }
+
+    #[test]
+    fn test_apply()
+    {
+
+        let transform = Transform2D::new((1.0, 2.0), std::f32::consts::FRAC_PI_2, 3.0);
+
+        assert!(point_equal(transform.apply((1.0, 0.0)), (1.0, 5.0)));
+        assert!(point_equal(transform.apply_vector((1.0, 0.0)), (0.0, 3.0)));
+
+        let inverse = transform.inverse();
+        assert!(point_equal(inverse.apply(transform.apply((-2.0, 7.0))), (-2.0, 7.0)));
+
+        let pivot = Transform2D::rotation_about((1.0, 1.0), std::f32::consts::PI);
+        assert!(point_equal(pivot.apply((2.0, 1.0)), (0.0, 1.0)));
+        assert!(point_equal(pivot.apply((1.0, 1.0)), (1.0, 1.0)));
+
+    }
+
+    #[test]
+    fn test_then()
+    {
+
+        let local = Transform2D::new((2.0, 0.0), std::f32::consts::FRAC_PI_2, 0.5);
+        let parent = Transform2D::new((-1.0, 3.0), std::f32::consts::FRAC_PI_4, 4.0);
+
+        let combined = local.then(&parent);
+
+        for point in [(0.0, 0.0), (1.0, -2.0), (3.5, 4.0)]
+        {
+
+            let expected = parent.apply(local.apply(point));
+            let actual = combined.apply(point);
+
+            assert!((expected.0 - actual.0).abs() < 0.0001);
+            assert!((expected.1 - actual.1).abs() < 0.0001);
+
+        }
+
+        assert_eq!(Transform2D::default().then(&parent), parent);
+
+    }
+
+    #[test]
+    fn test_transformed_shapes()
+    {
+
+        let transform = Transform2D::new((10.0, 0.0), std::f32::consts::FRAC_PI_2, 2.0);
+
+        let circle = Circle::new((1.0, 0.0), 1.0).transformed(&transform);
+        assert!(point_equal(circle.position, (10.0, 2.0)));
+        assert!(float_equal(circle.radius, 2.0));
+
+        let capsule = Capsule::new((0.0, 0.0), (1.0, 0.0), 0.5).transformed(&transform);
+        assert!(point_equal(capsule.arm(), (0.0, 2.0)));
+        assert!(float_equal(capsule.radius, 1.0));
+
+        let aabb = AABB::new((0.0, 0.0), 2.0, 1.0).transformed(&transform);
+        assert!(point_equal(aabb.u, (0.0, 4.0)));
+        assert!(point_equal(aabb.v, (-2.0, 0.0)));
+
+        let triangle = Polygon::from_vertices((0.0, 1.0), [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)].into()).transformed(&transform);
+        assert!(point_equal(triangle.position, (8.0, 0.0)));
+        assert!(point_equal(triangle.vertices[1], (0.0, 2.0)));
+
+    }
+
+    #[test]
+    fn test_transformed_collider()
+    {
+
+        let aabb = Collider::from(AABB::new((1.0, 1.0), 2.0, 2.0));
+
+        let moved = aabb.transformed(&Transform2D::new((1.0, 0.0), 0.0, 2.0));
+        assert!(matches!(moved, Collider::AABB(moved) if point_equal(moved.position, (3.0, 2.0)) && float_equal(moved.width, 4.0)));
+
+        let rotated = aabb.transformed(&Transform2D::from_rotation(1.0));
+        assert!(matches!(rotated, Collider::Parallelogram(_)));
+
+    }
+
+    #[test]
+    fn test_lazy_matches_copy()
+    {
+
+        let transform = Transform2D::new((3.0, -2.0), 0.7, 1.5);
+
+        let shapes: Vec<Collider> = vec!
+        [
+
+            Collider::from(Polygon::from_vertices((1.0, 0.5), [(0.0, 0.0), (2.0, 0.0), (1.0, 2.0)].into())),
+            Collider::from(Circle::new((0.5, 0.0), 1.0)),
+            Collider::from(AABB::new((-1.0, -1.0), 2.0, 1.0)),
+            Collider::from(Capsule::new((0.0, 1.0), (1.0, 0.5), 0.5)),
+            Collider::from(Parallelogram::new((0.0, -1.0), (1.0, 0.0), (0.5, 1.0))),
+            Collider::from(Point::new((0.5, 0.5))),
+            Collider::from(Segment::new((-1.0, 0.0), (1.0, 1.0)))
+
+        ];
+
+        let probes: Vec<Collider> = vec!
+        [
+
+            Collider::from(Circle::new((3.5, -1.0), 0.5)),
+            Collider::from(Circle::new((8.0, 4.0), 1.0)),
+            Collider::from(AABB::new((4.0, -0.5), 1.0, 3.0)),
+            Collider::from(Capsule::new((0.0, -4.0), (2.0, 0.0), 0.25))
+
+        ];
+
+        for shape in shapes.iter()
+        {
+
+            let copy = shape.transformed(&transform);
+            let lazy = Transformed::new(shape, transform);
+
+            assert!(point_equal(lazy.position(), copy.position()));
+
+            for probe in probes.iter()
+            {
+
+                assert_eq!(sat_overlap(&lazy, probe), sat_overlap(&copy, probe));
+                assert_eq!(sat_overlap(probe, &lazy), sat_overlap(probe, &copy));
+
+                let lazy_resolution = sat_collision(&lazy, probe);
+                let copy_resolution = sat_collision(&copy, probe);
+
+                assert!((lazy_resolution.0 - copy_resolution.0).abs() < 0.0001);
+                assert!((lazy_resolution.1 - copy_resolution.1).abs() < 0.0001);
+
+            }
+
+            for point in [(3.0, -2.0), (4.0, 0.0), (1.0, 1.0), (5.0, -3.0)]
+            {
+
+                assert_eq!(contains_point(&lazy, point), contains_point(&copy, point));
+                assert!((signed_distance(&lazy, point) - signed_distance(&copy, point)).abs() < 0.0001);
+
+            }
+
+        }
+
+    }
+
+    #[test]
+    fn test_lazy_set_position()
+    {
+
+        let circle = Circle::new((1.0, 0.0), 1.0);
+        let mut lazy = Transformed::new(&circle, Transform2D::from_rotation(std::f32::consts::FRAC_PI_2));
+
+        lazy.set_position((5.0, 5.0));
+
+        assert!(point_equal(lazy.position(), (5.0, 5.0)));
+        assert!(point_equal(circle.position, (1.0, 0.0)));
+        assert!(contains_point(&lazy, (5.5, 5.0)));
+
+    }
+
+    #[test]
+    fn test_rotate_about()
+    {
+
+        let mut capsule = Capsule::new((2.0, 0.0), (1.0, 0.0), 0.5);
+        capsule.rotate_about((0.0, 0.0), std::f32::consts::FRAC_PI_2);
+
+        assert!(point_equal(capsule.position, (0.0, 2.0)));
+        assert!(point_equal(capsule.arm(), (0.0, 1.0)));
+
+        let mut square = Polygon::from_vertices((1.0, 1.0), [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].into());
+        square.rotate_about((1.0, 1.0), std::f32::consts::PI);
+
+        assert!(point_equal(square.position, (1.0, 1.0)));
+        assert!(point_equal(square.vertices[2], (-1.0, -1.0)));
+
+        let mut circle = Circle::new((3.0, 1.0), 1.0);
+        circle.rotate_about((1.0, 1.0), std::f32::consts::PI);
+
+        assert!(point_equal(circle.position, (-1.0, 1.0)));
+
+    }
+
+}
@@ -0,0 +1,390 @@
+//! The container used for the vertices of a `Polygon`. With the `alloc` feature it is a `Vec`,
+//! and without it a `FixedVertices`, which stores up to `MAX_VERTICES` vertices inline.
+//!
+//! Code which should build either way can create vertices from an array, which both support:
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::vertices::Vertices;
+//!
+//! let triangle = Polygon::from_vertices((0.0, 0.0), Vertices::from([(0.0, 0.0), (2.0, 0.0), (1.0, 2.0)]));
+//!
+//! assert!(triangle.is_convex());
+//! ```
+
+use core::ops::{Deref, DerefMut};
+
+/// The vertices of a polygon.
+#[cfg(feature = "alloc")]
+pub type Vertices = alloc::vec::Vec<(f32, f32)>;
+
+/// The vertices of a polygon.
+#[cfg(not(feature = "alloc"))]
+pub type Vertices = FixedVertices;
+
+/// The number of vertices a `FixedVertices` can hold.
+pub const MAX_VERTICES: usize = 16;
+
+/// A list of at most `MAX_VERTICES` vertices which needs no allocation. It dereferences to
+/// a slice, so it can be read and modified like a `Vec` of fixed capacity.
+///
+/// # Examples
+///
+/// ```
+/// use sepax2d::vertices::FixedVertices;
+///
+/// let mut vertices = FixedVertices::from([(0.0, 0.0), (1.0, 0.0)]);
+/// vertices.push((0.0, 1.0));
+///
+/// assert_eq!(vertices.len(), 3);
+/// assert_eq!(vertices[2], (0.0, 1.0));
+/// ```
+#[derive(Clone)]
+pub struct FixedVertices
+{
+
+    vertices: [(f32, f32); MAX_VERTICES],
+    len: usize
+
+}
+
+impl FixedVertices
+{
+
+    /// Create an empty list of vertices.
+    pub const fn new() -> FixedVertices
+    {
+
+        return FixedVertices { vertices: [(0.0, 0.0); MAX_VERTICES], len: 0 };
+
+    }
+
+    /// The number of vertices the list can hold.
+    pub const fn capacity(&self) -> usize
+    {
+
+        return MAX_VERTICES;
+
+    }
+
+    /// Adds a vertex to the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list already holds `MAX_VERTICES` vertices.
+    pub fn push(&mut self, vertex: (f32, f32))
+    {
+
+        if self.try_push(vertex).is_err()
+        {
+
+            panic!("a polygon can have at most {} vertices without the alloc feature", MAX_VERTICES);
+
+        }
+
+    }
+
+    /// Adds a vertex to the end of the list, or returns it back if the list is full.
+    pub fn try_push(&mut self, vertex: (f32, f32)) -> Result<(), (f32, f32)>
+    {
+
+        if self.len == MAX_VERTICES
+        {
+
+            return Err(vertex);
+
+        }
+
+        self.vertices[self.len] = vertex;
+        self.len += 1;
+
+        return Ok(());
+
+    }
+
+    /// Removes the last vertex and returns it, or `None` if the list is empty.
+    pub fn pop(&mut self) -> Option<(f32, f32)>
+    {
+
+        if self.len == 0
+        {
+
+            return None;
+
+        }
+
+        self.len -= 1;
+
+        return Some(self.vertices[self.len]);
+
+    }
+
+    /// Removes the vertex at the given index and returns it, shifting the following vertices down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> (f32, f32)
+    {
+
+        let vertex = self[index];
+
+        self.vertices.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+
+        return vertex;
+
+    }
+
+    /// Removes all vertices.
+    pub fn clear(&mut self)
+    {
+
+        self.len = 0;
+
+    }
+
+}
+
+impl Default for FixedVertices
+{
+
+    fn default() -> FixedVertices
+    {
+
+        return FixedVertices::new();
+
+    }
+
+}
+
+impl Deref for FixedVertices
+{
+
+    type Target = [(f32, f32)];
+
+    fn deref(&self) -> &[(f32, f32)]
+    {
+
+        return &self.vertices[..self.len];
+
+    }
+
+}
+
+impl DerefMut for FixedVertices
+{
+
+    fn deref_mut(&mut self) -> &mut [(f32, f32)]
+    {
+
+        return &mut self.vertices[..self.len];
+
+    }
+
+}
+
+impl core::fmt::Debug for FixedVertices
+{
+
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+
+        return formatter.debug_list().entries(self.iter()).finish();
+
+    }
+
+}
+
+impl PartialEq for FixedVertices
+{
+
+    fn eq(&self, other: &FixedVertices) -> bool
+    {
+
+        return **self == **other;
+
+    }
+
+}
+
+/// # Panics
+///
+/// Panics if the iterator yields more than `MAX_VERTICES` vertices.
+impl FromIterator<(f32, f32)> for FixedVertices
+{
+
+    fn from_iter<I: IntoIterator<Item = (f32, f32)>>(iter: I) -> FixedVertices
+    {
+
+        let mut vertices = FixedVertices::new();
+
+        for vertex in iter
+        {
+
+            vertices.push(vertex);
+
+        }
+
+        return vertices;
+
+    }
+
+}
+
+/// # Panics
+///
+/// Panics if the slice has more than `MAX_VERTICES` vertices.
+impl From<&[(f32, f32)]> for FixedVertices
+{
+
+    fn from(slice: &[(f32, f32)]) -> FixedVertices
+    {
+
+        return slice.iter().copied().collect();
+
+    }
+
+}
+
+/// # Panics
+///
+/// Panics if the array has more than `MAX_VERTICES` vertices.
+impl<const N: usize> From<[(f32, f32); N]> for FixedVertices
+{
+
+    fn from(array: [(f32, f32); N]) -> FixedVertices
+    {
+
+        return array.into_iter().collect();
+
+    }
+
+}
+
+impl<'a> IntoIterator for &'a FixedVertices
+{
+
+    type Item = &'a (f32, f32);
+    type IntoIter = core::slice::Iter<'a, (f32, f32)>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+
+        return self.iter();
+
+    }
+
+}
+
+impl<'a> IntoIterator for &'a mut FixedVertices
+{
+
+    type Item = &'a mut (f32, f32);
+    type IntoIter = core::slice::IterMut<'a, (f32, f32)>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+
+        return self.iter_mut();
+
+    }
+
+}
+
+#[cfg(test)]
+mod vertices_tests
+{
+
+    use super::*;
+    use crate::Shape;
+    use crate::polygon::Polygon;
+
+    #[test]
+    fn test_push_and_pop()
+    {
+
+        let mut vertices = FixedVertices::new();
+
+        assert!(vertices.is_empty());
+        assert_eq!(vertices.pop(), None);
+
+        vertices.push((1.0, 2.0));
+        vertices.push((3.0, 4.0));
+
+        assert_eq!(vertices.len(), 2);
+        assert_eq!(vertices.first(), Some(&(1.0, 2.0)));
+        assert_eq!(vertices.pop(), Some((3.0, 4.0)));
+        assert_eq!(vertices.len(), 1);
+
+        vertices.push((5.0, 6.0));
+        vertices.push((7.0, 8.0));
+
+        assert_eq!(vertices.remove(1), (5.0, 6.0));
+        assert_eq!(vertices, FixedVertices::from([(1.0, 2.0), (7.0, 8.0)]));
+
+        vertices.clear();
+
+        assert!(vertices.is_empty());
+
+    }
+
+    #[test]
+    fn test_capacity()
+    {
+
+        let mut vertices: FixedVertices = (0..MAX_VERTICES).map(|i| (i as f32, 0.0)).collect();
+
+        assert_eq!(vertices.len(), vertices.capacity());
+        assert_eq!(vertices.try_push((-1.0, -1.0)), Err((-1.0, -1.0)));
+        assert_eq!(vertices[MAX_VERTICES - 1], ((MAX_VERTICES - 1) as f32, 0.0));
+
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_past_capacity()
+    {
+
+        let mut vertices = FixedVertices::from([(0.0, 0.0); MAX_VERTICES]);
+        vertices.push((1.0, 1.0));
+
+    }
+
+    #[test]
+    fn test_modify_in_place()
+    {
+
+        let mut vertices = FixedVertices::from([(0.0, 0.0), (2.0, 0.0), (1.0, 2.0)]);
+
+        for vertex in &mut vertices
+        {
+
+            vertex.1 += 1.0;
+
+        }
+
+        assert_eq!(vertices, FixedVertices::from(&[(0.0, 1.0), (2.0, 1.0), (1.0, 3.0)][..]));
+
+    }
+
+    #[test]
+    fn test_matches_vec()
+    {
+
+        //Both containers must describe the same polygon
+        let fixed = FixedVertices::from([(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let square = Polygon::from_vertices((1.0, 1.0), Vertices::from(&fixed[..]));
+
+        assert_eq!(square.vertices.len(), fixed.len());
+
+        for i in 0..fixed.len()
+        {
+
+            assert_eq!(square.point(i), (1.0 + fixed[i].0, 1.0 + fixed[i].1));
+
+        }
+
+    }
+
+}
@@ -0,0 +1,365 @@
+//! Visibility queries, for finding the region which can be seen from a point when
+//! other shapes block the view.
+//!
+//! The visible region is found by casting rays outward from the eye, so it is an
+//! approximation whose accuracy depends on the number of rays. Wherever two neighbouring
+//! rays stop at very different distances, the edge of the occluder in between is found
+//! by bisecting the angle, so the silhouettes of occluders stay sharp even with few rays.
+//!
+//! # Examples
+//!
+//! ```
+//! use sepax2d::prelude::*;
+//! use sepax2d::visibility::{visibility_polygon, fan_triangles, line_of_sight};
+//!
+//! let wall = AABB::new((3.0, -1.0), 2.0, 2.0);
+//! let pillar = Circle::new((-4.0, 0.0), 1.0);
+//! let occluders: [&dyn Shape; 2] = [&wall, &pillar];
+//!
+//! let visible = visibility_polygon((0.0, 0.0), 10.0, &occluders, 64);
+//! let triangles = fan_triangles(&visible);
+//!
+//! assert!(!triangles.iter().any(|triangle| contains_point(triangle, (7.0, 0.0))));
+//! assert!(triangles.iter().any(|triangle| contains_point(triangle, (0.0, 7.0))));
+//!
+//! assert!(!line_of_sight((0.0, 0.0), (7.0, 0.0), &occluders));
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Shape, contains_point};
+use crate::polygon::Polygon;
+use crate::line::intersects_segment;
+use crate::math;
+
+//Number of times the angle between two rays is halved when looking for the edge of an occluder
+const EDGE_ITERATIONS: usize = 16;
+
+//Upper bound on the number of halvings when searching for the distance along a single ray
+const DISTANCE_ITERATIONS: usize = 32;
+
+/// Computes the region visible from the eye within the given radius, with the view blocked
+/// by the given occluders. At least three rays are cast, evenly spaced around the eye.
+///
+/// The result is a polygon positioned at the eye whose vertices are in counterclockwise order
+/// of angle around the eye. It is star-shaped around the eye rather than convex, so it should
+/// not be passed directly to the SAT functions; use `fan_triangles` to split it into triangles.
+/// If the eye is inside of an occluder, nothing is visible and every vertex is at the eye.
+pub fn visibility_polygon(eye: (f32, f32), radius: f32, occluders: &[&dyn Shape], rays: usize) -> Polygon
+{
+
+    let rays = usize::max(rays, 3);
+    let mut polygon = Polygon::new(eye);
+
+    if occluders.iter().any(|occluder| contains_point(*occluder, eye))
+    {
+
+        polygon.vertices = vec![(0.0, 0.0); rays];
+        return polygon;
+
+    }
+
+    let step = core::f32::consts::TAU / rays as f32;
+    let tolerance = radius * 0.001;
+
+    let distances: Vec<f32> = (0..rays).map(|i| ray_distance(eye, direction(step * i as f32), radius, occluders)).collect();
+
+    for i in 0..rays
+    {
+
+        let angle = step * i as f32;
+        let next = (i + 1) % rays;
+
+        polygon.vertices.push(along(direction(angle), distances[i]));
+
+        //Along a surface facing the eye, the distance changes by roughly the distance times the step
+        //between rays, so anything much larger than that is the edge of an occluder
+        let threshold = (f32::min(distances[i], distances[next]) * step * 2.0) + tolerance;
+
+        if (distances[i] - distances[next]).abs() <= threshold
+        {
+
+            continue;
+
+        }
+
+        //Bisect the angle until the two sides of the edge are found
+        let mut low = (angle, distances[i]);
+        let mut high = (angle + step, distances[next]);
+
+        for _ in 0..EDGE_ITERATIONS
+        {
+
+            let middle = (low.0 + high.0) * 0.5;
+            let distance = ray_distance(eye, direction(middle), radius, occluders);
+
+            if (distance - low.1).abs() < (distance - high.1).abs()
+            {
+
+                low = (middle, distance);
+
+            }
+            else
+            {
+
+                high = (middle, distance);
+
+            }
+
+        }
+
+        polygon.vertices.push(along(direction(low.0), low.1));
+        polygon.vertices.push(along(direction(high.0), high.1));
+
+    }
+
+    return polygon;
+
+}
+
+/// Splits a polygon returned by `visibility_polygon` into the triangles of its fan, each of
+/// which is convex and can be used with the SAT functions. Triangles with no area are skipped.
+pub fn fan_triangles(fan: &Polygon) -> Vec<Polygon>
+{
+
+    let count = fan.vertices.len();
+    let mut triangles = Vec::with_capacity(count);
+
+    for i in 0..count
+    {
+
+        let current = fan.vertices[i];
+        let next = fan.vertices[(i + 1) % count];
+
+        let cross = (current.0 * next.1) - (current.1 * next.0);
+
+        if cross > f32::EPSILON
+        {
+
+            triangles.push(Polygon::from_vertices(fan.position, vec![(0.0, 0.0), current, next]));
+
+        }
+
+    }
+
+    return triangles;
+
+}
+
+/// Checks if the target can be seen from the eye, i.e. if the line segment between the two
+/// points does not intersect any of the occluders.
+pub fn line_of_sight(eye: (f32, f32), target: (f32, f32), occluders: &[&dyn Shape]) -> bool
+{
+
+    return !occluders.iter().any(|occluder| intersects_segment(*occluder, eye, target));
+
+}
+
+//Distance along the normalized direction from the eye to the first occluder, or the radius if none is hit
+fn ray_distance(eye: (f32, f32), direction: (f32, f32), radius: f32, occluders: &[&dyn Shape]) -> f32
+{
+
+    let tolerance = f32::max(radius * 0.00001, f32::EPSILON);
+    let start = (eye.0 * direction.0) + (eye.1 * direction.1);
+
+    let mut nearest = radius;
+
+    for occluder in occluders
+    {
+
+        if !intersects_segment(*occluder, eye, offset(eye, direction, nearest))
+        {
+
+            continue;
+
+        }
+
+        //The shape's extent along the ray bounds where the ray can first enter it
+        let projection = occluder.project(direction, false);
+
+        let mut low = f32::max(projection.0 - start, 0.0);
+        let mut high = f32::min(projection.1 - start, nearest);
+
+        for _ in 0..DISTANCE_ITERATIONS
+        {
+
+            if high - low <= tolerance
+            {
+
+                break;
+
+            }
+
+            let middle = (low + high) * 0.5;
+
+            if intersects_segment(*occluder, eye, offset(eye, direction, middle))
+            {
+
+                high = middle;
+
+            }
+            else
+            {
+
+                low = middle;
+
+            }
+
+        }
+
+        nearest = high;
+
+    }
+
+    return nearest;
+
+}
+
+fn direction(angle: f32) -> (f32, f32)
+{
+
+    return (math::cos(angle), math::sin(angle));
+
+}
+
+fn along(direction: (f32, f32), distance: f32) -> (f32, f32)
+{
+
+    return (direction.0 * distance, direction.1 * distance);
+
+}
+
+fn offset(point: (f32, f32), direction: (f32, f32), distance: f32) -> (f32, f32)
+{
+
+    return (point.0 + direction.0 * distance, point.1 + direction.1 * distance);
+
+}
+
+#[cfg(test)]
+mod visibility_tests
+{
+
+    use super::*;
+    use crate::float_equal;
+    use crate::aabb::AABB;
+    use crate::circle::Circle;
+
+    fn visible(triangles: &[Polygon], point: (f32, f32)) -> bool
+    {
+
+        return triangles.iter().any(|triangle| contains_point(triangle, point));
+
+    }
+
+    #[test]
+    fn test_no_occluders()
+    {
+
+        let polygon = visibility_polygon((1.0, 2.0), 5.0, &[], 32);
+
+        assert_eq!(polygon.position, (1.0, 2.0));
+        assert_eq!(polygon.vertices.len(), 32);
+        assert!(polygon.vertices.iter().all(|vertex| float_equal(f32::sqrt(vertex.0 * vertex.0 + vertex.1 * vertex.1), 5.0)));
+
+        let first = polygon.vertices[0];
+        let second = polygon.vertices[1];
+
+        assert!((first.0 * second.1) - (first.1 * second.0) > 0.0);
+
+    }
+
+    #[test]
+    fn test_occluded_region()
+    {
+
+        let wall = AABB::new((3.0, -1.0), 2.0, 2.0);
+        let occluders: [&dyn Shape; 1] = [&wall];
+
+        let polygon = visibility_polygon((0.0, 0.0), 10.0, &occluders, 64);
+
+        assert!((polygon.vertices[0].0 - 3.0).abs() < 0.001);
+        assert!(polygon.vertices[0].1.abs() < 0.001);
+
+        let triangles = fan_triangles(&polygon);
+
+        assert!(visible(&triangles, (2.0, 0.5)));
+        assert!(visible(&triangles, (6.0, 5.0)));
+        assert!(visible(&triangles, (-7.0, -2.0)));
+        assert!(!visible(&triangles, (6.0, 0.0)));
+        assert!(!visible(&triangles, (9.0, 2.5)));
+        assert!(!visible(&triangles, (12.0, 0.0)));
+
+    }
+
+    #[test]
+    fn test_sharp_silhouette()
+    {
+
+        let wall = AABB::new((3.0, -1.0), 2.0, 2.0);
+        let occluders: [&dyn Shape; 1] = [&wall];
+
+        //Few rays, but the corners of the wall's silhouette are still found
+        let polygon = visibility_polygon((0.0, 0.0), 10.0, &occluders, 8);
+
+        for corner in [(3.0, 1.0), (3.0, -1.0)]
+        {
+
+            assert!(polygon.vertices.iter().any(|vertex| f32::hypot(vertex.0 - corner.0, vertex.1 - corner.1) < 0.01));
+
+        }
+
+    }
+
+    #[test]
+    fn test_round_occluder()
+    {
+
+        let pillar = Circle::new((0.0, 5.0), 1.0);
+        let occluders: [&dyn Shape; 1] = [&pillar];
+
+        let polygon = visibility_polygon((0.0, 0.0), 10.0, &occluders, 16);
+
+        let up = polygon.vertices.iter().find(|vertex| vertex.0.abs() < 0.001 && vertex.1 > 0.0).unwrap();
+        assert!((up.1 - 4.0).abs() < 0.001);
+
+        let triangles = fan_triangles(&polygon);
+
+        assert!(visible(&triangles, (0.5, 3.5)));
+        assert!(!visible(&triangles, (0.0, 7.0)));
+        assert!(visible(&triangles, (3.0, 7.0)));
+
+    }
+
+    #[test]
+    fn test_eye_inside_occluder()
+    {
+
+        let wall = AABB::new((-1.0, -1.0), 2.0, 2.0);
+        let occluders: [&dyn Shape; 1] = [&wall];
+
+        let polygon = visibility_polygon((0.0, 0.0), 10.0, &occluders, 16);
+
+        assert_eq!(polygon.vertices.len(), 16);
+        assert!(polygon.vertices.iter().all(|vertex| *vertex == (0.0, 0.0)));
+        assert!(fan_triangles(&polygon).is_empty());
+
+    }
+
+    #[test]
+    fn test_line_of_sight()
+    {
+
+        let wall = AABB::new((3.0, -1.0), 2.0, 2.0);
+        let pillar = Circle::new((0.0, 5.0), 1.0);
+        let occluders: [&dyn Shape; 2] = [&wall, &pillar];
+
+        assert!(line_of_sight((0.0, 0.0), (2.0, 0.0), &occluders));
+        assert!(line_of_sight((0.0, 0.0), (3.0, 3.0), &occluders));
+        assert!(!line_of_sight((0.0, 0.0), (6.0, 0.0), &occluders));
+        assert!(!line_of_sight((0.0, 0.0), (0.0, 8.0), &occluders));
+
+    }
+
+}
@@ -0,0 +1,528 @@
+//! Bosses described in `.boss.ron` asset files. A boss is a parent entity which moves and
+//! shoots, with its damageable parts as children. Each part has its own collider, placed
+//! relative to the boss so it follows the boss as it moves and turns.
+//!
+//! The boss changes phase as its remaining health, summed over all parts, drops below each
+//! phase's threshold. Every phase brings its own movement pattern and emitter. Destroying the
+//! core, or every part if the boss has no core, defeats the boss, and armor or turrets covering
+//! the core take bullets meant for it. Parts hurt the player on contact without being destroyed.
+//!
+//! A defeated boss is reported with `BossKilled` rather than `EnemyKilled`, carrying the points
+//! and drop table from its definition.
+//!
+//! ```ron
+//! (
+//!     name: "Warden",
+//!     half_size: (40.0, 20.0),
+//!     points: 5000,
+//!     drops: Some((chance: 1.0, drops: [(WeaponUpgrade, 1)])),
+//!     parts: [
+//!         (kind: Core, half_size: (10.0, 10.0), health: 40.0),
+//!         (kind: Turret, offset: (-30.0, -16.0), half_size: (6.0, 6.0), health: 15.0,
+//!             emitter: Some((pattern: Aimed(count: 1), cooldown: 1.2))),
+//!     ],
+//!     phases: [
+//!         (health: 1.0, pattern: Some(Path(points: [(0.0, 0.0), (0.0, -90.0)], duration: 2.0))),
+//!         (health: 0.5, pattern: Some(Sine(speed: 0.0, amplitude: 80.0, frequency: 0.25)),
+//!             emitter: Some((pattern: Radial(count: 16), cooldown: 1.0))),
+//!     ],
+//! )
+//! ```
+
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use sepax::prelude::sat_overlap;
+use serde::Deserialize;
+
+use crate::emitter::{Emitter, EmitterDef};
+use crate::health::{ContactDamage, Persistent};
+use crate::pattern::{MovementPattern, Pattern};
+use crate::pickup::DropTable;
+use crate::ron_asset::RonLoader;
+use crate::state::GameplaySet;
+use crate::{hitbox, Bullet, Damage, Movement, AABB};
+
+#[derive(Deserialize, TypeUuid, TypePath)]
+#[uuid = "2f9c7d13-48ab-4e6f-8c05-b1d7a3e64f92"]
+pub struct BossDef {
+    pub name: String,
+    pub half_size: (f32, f32),
+    /// The points awarded for defeating the boss, before multipliers.
+    #[serde(default)]
+    pub points: usize,
+    #[serde(default)]
+    pub drops: Option<DropTable>,
+    pub parts: Vec<PartDef>,
+    /// Phases in order, each starting once the boss's health drops to its threshold.
+    pub phases: Vec<PhaseDef>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PartKind {
+    Core,
+    Turret,
+    Armor,
+}
+
+impl PartKind {
+    fn color(&self) -> Color {
+        match self {
+            PartKind::Core => Color::rgb(1.0, 0.3, 0.3),
+            PartKind::Turret => Color::rgb(0.9, 0.8, 0.3),
+            PartKind::Armor => Color::rgb(0.6, 0.6, 0.7),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PartDef {
+    pub kind: PartKind,
+    /// Relative to the center of the boss.
+    #[serde(default)]
+    pub offset: (f32, f32),
+    /// Radians counterclockwise.
+    #[serde(default)]
+    pub rotation: f32,
+    pub half_size: (f32, f32),
+    pub health: f32,
+    #[serde(default)]
+    pub emitter: Option<EmitterDef>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PhaseDef {
+    /// The fraction of the boss's total health at or below which the phase starts.
+    pub health: f32,
+    #[serde(default)]
+    pub pattern: Option<Pattern>,
+    #[serde(default)]
+    pub emitter: Option<EmitterDef>,
+}
+
+#[derive(Component)]
+pub struct Boss {
+    phases: Vec<PhaseDef>,
+    phase: Option<usize>,
+    max_health: f32,
+    points: usize,
+    drops: Option<DropTable>,
+}
+
+/// A boss waiting for its definition to load before it can be spawned.
+#[derive(Component)]
+pub struct PendingBoss {
+    def: Handle<BossDef>,
+    position: Vec2,
+}
+
+impl PendingBoss {
+    pub fn new(def: Handle<BossDef>, position: Vec2) -> Self {
+        Self { def, position }
+    }
+}
+
+#[derive(Component)]
+pub struct BossPart {
+    pub kind: PartKind,
+    pub health: f32,
+}
+
+#[derive(Component)]
+pub struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthFill;
+
+/// Sent when a boss enters a new phase, including the first one.
+#[derive(Event, Clone, Copy)]
+pub struct BossPhaseChanged {
+    pub boss: Entity,
+    pub phase: usize,
+}
+
+/// Sent when a boss is defeated.
+#[derive(Event, Clone)]
+pub struct BossKilled {
+    pub position: Vec2,
+    pub points: usize,
+    pub drops: Option<DropTable>,
+}
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<BossDef>()
+            .add_asset_loader(RonLoader::<BossDef>::new(&["boss.ron"]))
+            .add_event::<BossPhaseChanged>()
+            .add_event::<BossKilled>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    spawn_pending_bosses,
+                    damage_boss_parts.after(crate::check_collisions),
+                    update_boss_phases,
+                    defeat_bosses,
+                )
+                    .chain()
+                    .in_set(GameplaySet),
+            )
+            .add_systems(Update, update_health_bar);
+    }
+}
+
+fn spawn_pending_bosses(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    asset_server: Res<AssetServer>,
+    bosses: Res<Assets<BossDef>>,
+    query: Query<(Entity, &PendingBoss)>,
+) {
+    for (entity, pending) in &query {
+        let Some(def) = bosses.get(&pending.def) else {
+            continue;
+        };
+
+        commands.entity(entity).despawn_recursive();
+
+        let max_health = def.parts.iter().map(|part| part.health).sum();
+
+        commands
+            .spawn((
+                Boss {
+                    phases: def.phases.clone(),
+                    phase: None,
+                    max_health,
+                    points: def.points,
+                    drops: def.drops.clone(),
+                },
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::rgb(0.3, 0.3, 0.35),
+                        custom_size: Some(Vec2::from(def.half_size) * 2.0),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(pending.position.x, pending.position.y, 1.0),
+                    ..default()
+                },
+                AABB {
+                    half_size: def.half_size.into(),
+                },
+                Movement::default(),
+            ))
+            .with_children(|parent| {
+                for part in &def.parts {
+                    let mut child = parent.spawn((
+                        BossPart {
+                            kind: part.kind,
+                            health: part.health,
+                        },
+                        SpriteBundle {
+                            sprite: Sprite {
+                                color: part.kind.color(),
+                                custom_size: Some(Vec2::from(part.half_size) * 2.0),
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(part.offset.0, part.offset.1, 0.1)
+                                .with_rotation(Quat::from_rotation_z(part.rotation)),
+                            ..default()
+                        },
+                        AABB {
+                            half_size: part.half_size.into(),
+                        },
+                        ContactDamage(1.0),
+                        Persistent,
+                    ));
+
+                    if let Some(emitter) = &part.emitter {
+                        child.insert(Emitter::new(emitter.clone(), time.period));
+                    }
+                }
+            });
+
+        commands
+            .spawn((
+                BossHealthBar,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(10.0),
+                        left: Val::Percent(25.0),
+                        width: Val::Percent(50.0),
+                        height: Val::Px(8.0),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.2, 0.2, 0.2).into(),
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    BossHealthFill,
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: Color::rgb(0.9, 0.2, 0.2).into(),
+                        ..default()
+                    },
+                ));
+
+                parent.spawn(
+                    TextBundle::from_section(
+                        def.name.clone(),
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: 12.0,
+                            color: Color::WHITE,
+                        },
+                    )
+                    .with_style(Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(10.0),
+                        ..default()
+                    }),
+                );
+            });
+    }
+}
+
+fn damage_boss_parts(
+    mut commands: Commands,
+    query_boss: Query<&Transform, With<Boss>>,
+    mut query_part: Query<(Entity, &mut BossPart, &Parent, &Transform, &AABB)>,
+    query_bullet: Query<(Entity, &Transform, &AABB, Option<&Damage>), With<Bullet>>,
+) {
+    for (entity_bullet, transform_bullet, aabb_bullet, damage) in &query_bullet {
+        let bullet = hitbox(transform_bullet, aabb_bullet);
+
+        //Armor and turrets in front of the core take the hit instead of it
+        let Some(entity_part) = query_part
+            .iter()
+            .filter(|(_, part, parent, transform, aabb)| {
+                let Ok(transform_boss) = query_boss.get(parent.get()) else {
+                    return false;
+                };
+                let world = transform_boss.mul_transform(**transform);
+
+                part.health > 0.0 && sat_overlap(&hitbox(&world, aabb), &bullet)
+            })
+            .min_by_key(|(_, part, ..)| part.kind == PartKind::Core)
+            .map(|(entity, ..)| entity)
+        else {
+            continue;
+        };
+
+        let Ok((_, mut part, ..)) = query_part.get_mut(entity_part) else {
+            continue;
+        };
+
+        commands.entity(entity_bullet).despawn_recursive();
+        part.health -= damage.map_or(1.0, |damage| damage.0);
+
+        //A destroyed core stays until the boss goes with it
+        if part.health <= 0.0 && part.kind != PartKind::Core {
+            commands.entity(entity_part).despawn_recursive();
+        }
+    }
+}
+
+/// Whether the boss with these parts is defeated: its core is destroyed, or all of its parts are.
+fn is_defeated<'a>(parts: impl IntoIterator<Item = &'a BossPart>) -> bool {
+    let mut all_destroyed = true;
+
+    for part in parts {
+        let destroyed = part.health <= 0.0;
+
+        if destroyed && part.kind == PartKind::Core {
+            return true;
+        }
+        all_destroyed &= destroyed;
+    }
+
+    all_destroyed
+}
+
+/// The boss's remaining health summed over its parts, as a fraction of its starting health.
+fn health_fraction<'a>(parts: impl IntoIterator<Item = &'a BossPart>, max_health: f32) -> f32 {
+    let health: f32 = parts.into_iter().map(|part| part.health.max(0.0)).sum();
+
+    (health / max_health.max(f32::EPSILON)).clamp(0.0, 1.0)
+}
+
+/// The phase to switch to with `fraction` of the boss's health left, if it comes after the
+/// `current` one. Phases only ever move forward.
+fn next_phase(phases: &[PhaseDef], current: Option<usize>, fraction: f32) -> Option<usize> {
+    let phase = phases.iter().rposition(|phase| fraction <= phase.health)?;
+
+    if current.is_some_and(|current| current >= phase) {
+        return None;
+    }
+
+    Some(phase)
+}
+
+fn update_boss_phases(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    mut phase_changed: EventWriter<BossPhaseChanged>,
+    mut query_boss: Query<(Entity, &mut Boss, &Transform, Option<&Children>)>,
+    query_part: Query<&BossPart>,
+) {
+    for (entity, mut boss, transform, children) in &mut query_boss {
+        let parts = || query_part.iter_many(children.into_iter().flatten());
+
+        //A defeated boss is despawned this tick, so there is nothing to change
+        if is_defeated(parts()) {
+            continue;
+        }
+
+        let fraction = health_fraction(parts(), boss.max_health);
+        let Some(phase) = next_phase(&boss.phases, boss.phase, fraction) else {
+            continue;
+        };
+        boss.phase = Some(phase);
+
+        let def = &boss.phases[phase];
+        let mut entity_commands = commands.entity(entity);
+
+        match &def.pattern {
+            Some(pattern) => entity_commands.insert(MovementPattern::new(
+                pattern.clone(),
+                transform.translation.truncate(),
+            )),
+            None => entity_commands.remove::<MovementPattern>(),
+        };
+
+        match &def.emitter {
+            Some(emitter) => entity_commands.insert(Emitter::new(emitter.clone(), time.period)),
+            None => entity_commands.remove::<Emitter>(),
+        };
+
+        phase_changed.send(BossPhaseChanged {
+            boss: entity,
+            phase,
+        });
+    }
+}
+
+fn defeat_bosses(
+    mut commands: Commands,
+    mut boss_killed: EventWriter<BossKilled>,
+    query_boss: Query<(Entity, &Boss, &Transform, Option<&Children>)>,
+    query_part: Query<&BossPart>,
+) {
+    for (entity, boss, transform, children) in &query_boss {
+        if !is_defeated(query_part.iter_many(children.into_iter().flatten())) {
+            continue;
+        }
+
+        commands.entity(entity).despawn_recursive();
+
+        boss_killed.send(BossKilled {
+            position: transform.translation.truncate(),
+            points: boss.points,
+            drops: boss.drops.clone(),
+        });
+    }
+}
+
+fn update_health_bar(
+    mut commands: Commands,
+    query_boss: Query<(&Boss, &Children)>,
+    query_part: Query<&BossPart>,
+    query_bar: Query<Entity, With<BossHealthBar>>,
+    mut query_fill: Query<&mut Style, With<BossHealthFill>>,
+) {
+    //The bar belongs to the boss which is alive, and goes away with it
+    let Ok((boss, children)) = query_boss.get_single() else {
+        for entity in &query_bar {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let fraction = health_fraction(query_part.iter_many(children), boss.max_health);
+
+    for mut style in &mut query_fill {
+        style.width = Val::Percent(fraction * 100.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phases(thresholds: &[f32]) -> Vec<PhaseDef> {
+        thresholds
+            .iter()
+            .map(|&health| PhaseDef {
+                health,
+                pattern: None,
+                emitter: None,
+            })
+            .collect()
+    }
+
+    fn part(kind: PartKind, health: f32) -> BossPart {
+        BossPart { kind, health }
+    }
+
+    #[test]
+    fn phase_follows_health_fraction() {
+        let phases = phases(&[1.0, 0.7, 0.35]);
+
+        assert_eq!(next_phase(&phases, None, 1.0), Some(0));
+        assert_eq!(next_phase(&phases, None, 0.7), Some(1));
+        assert_eq!(next_phase(&phases, Some(0), 0.5), Some(1));
+        assert_eq!(next_phase(&phases, Some(1), 0.1), Some(2));
+        assert_eq!(next_phase(&phases, Some(2), 0.0), None);
+    }
+
+    #[test]
+    fn phases_only_move_forward() {
+        let phases = phases(&[1.0, 0.5]);
+
+        assert_eq!(next_phase(&phases, Some(0), 0.9), None);
+        assert_eq!(next_phase(&phases, Some(1), 0.9), None);
+    }
+
+    #[test]
+    fn phase_waits_for_its_threshold() {
+        let phases = phases(&[0.8]);
+
+        assert_eq!(next_phase(&phases, None, 1.0), None);
+        assert_eq!(next_phase(&phases, None, 0.8), Some(0));
+        assert_eq!(next_phase(&[], None, 0.0), None);
+    }
+
+    #[test]
+    fn health_fraction_ignores_overkill() {
+        let parts = [part(PartKind::Core, 30.0), part(PartKind::Armor, -20.0)];
+
+        assert_eq!(health_fraction(&parts, 60.0), 0.5);
+        assert_eq!(health_fraction(&[], 60.0), 0.0);
+        assert_eq!(health_fraction(&[], 0.0), 0.0);
+    }
+
+    #[test]
+    fn destroyed_core_defeats_boss() {
+        let parts = [part(PartKind::Core, 0.0), part(PartKind::Armor, 10.0)];
+        assert!(is_defeated(&parts));
+
+        let parts = [part(PartKind::Core, 5.0), part(PartKind::Armor, 0.0)];
+        assert!(!is_defeated(&parts));
+    }
+
+    #[test]
+    fn coreless_boss_is_defeated_with_its_parts() {
+        let parts = [part(PartKind::Turret, 0.0), part(PartKind::Armor, 10.0)];
+        assert!(!is_defeated(&parts));
+
+        let parts = [part(PartKind::Turret, 0.0), part(PartKind::Armor, -1.0)];
+        assert!(is_defeated(&parts));
+        assert!(is_defeated(&[]));
+    }
+}
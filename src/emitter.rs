@@ -0,0 +1,412 @@
+//! Bullet emitters for enemies. An `Emitter` fires a volley in its pattern every time its
+//! cooldown runs out, as long as fewer than `max_bullets` of its bullets are alive.
+//!
+//! Angles are in radians counterclockwise from the x axis, durations in seconds and speeds
+//! in world units per second. Enemy bullets are marked with `EnemyBullet` and hurt the
+//! player through `ContactDamage`, so they never hit other enemies.
+
+use std::collections::HashMap;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::health::ContactDamage;
+use crate::state::GameplaySet;
+use crate::{AutoDespawn, Lifetime, Movement, Player, AABB};
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum EmitterPattern {
+    /// Shots aimed at the player, spread evenly over `spread` radians.
+    Aimed {
+        count: usize,
+        #[serde(default)]
+        spread: f32,
+    },
+    /// Shots in every direction.
+    Radial { count: usize },
+    /// Shots in every direction, turned by `turn` radians after every volley.
+    Spiral { count: usize, turn: f32 },
+    /// Shots spread evenly over `spread` radians around a fixed direction.
+    Fan {
+        count: usize,
+        spread: f32,
+        #[serde(default = "down")]
+        direction: f32,
+    },
+    /// A fan which sweeps from side to side by `sweep` radians, `frequency` times per second.
+    Wave {
+        count: usize,
+        spread: f32,
+        sweep: f32,
+        frequency: f32,
+    },
+}
+
+fn down() -> f32 {
+    -FRAC_PI_2
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct BulletDef {
+    pub speed: f32,
+    /// The speed the bullet reaches after `ramp` seconds, if it should change.
+    #[serde(default)]
+    pub end_speed: Option<f32>,
+    #[serde(default)]
+    pub ramp: f32,
+    pub damage: f32,
+    pub lifetime: f32,
+}
+
+impl Default for BulletDef {
+    fn default() -> Self {
+        Self {
+            speed: 120.0,
+            end_speed: None,
+            ramp: 0.0,
+            damage: 1.0,
+            lifetime: 6.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EmitterDef {
+    pub pattern: EmitterPattern,
+    pub cooldown: f32,
+    #[serde(default)]
+    pub bullet: BulletDef,
+    #[serde(default = "default_max_bullets")]
+    pub max_bullets: usize,
+}
+
+fn default_max_bullets() -> usize {
+    64
+}
+
+#[derive(Component)]
+pub struct Emitter {
+    def: EmitterDef,
+    timer: Timer,
+    volleys: u32,
+}
+
+impl Emitter {
+    /// Creates an emitter for a simulation ticking every `period`. Cooldowns are at least one
+    /// tick long.
+    pub fn new(def: EmitterDef, period: Duration) -> Self {
+        let cooldown = Duration::from_secs_f32(def.cooldown.max(0.0)).max(period);
+
+        Self {
+            timer: Timer::new(cooldown, TimerMode::Repeating),
+            def,
+            volleys: 0,
+        }
+    }
+
+    /// The directions of the next volley, given the direction to the player.
+    fn directions(&self, aim: f32) -> Vec<f32> {
+        match self.def.pattern {
+            EmitterPattern::Aimed { count, spread } => fan(aim, count, spread),
+            EmitterPattern::Radial { count } => ring(0.0, count),
+            EmitterPattern::Spiral { count, turn } => ring(turn * self.volleys as f32, count),
+            EmitterPattern::Fan {
+                count,
+                spread,
+                direction,
+            } => fan(direction, count, spread),
+            EmitterPattern::Wave {
+                count,
+                spread,
+                sweep,
+                frequency,
+            } => {
+                let t = self.volleys as f32 * self.timer.duration().as_secs_f32();
+
+                fan(down() + sweep * (TAU * frequency * t).sin(), count, spread)
+            }
+        }
+    }
+}
+
+fn fan(direction: f32, count: usize, spread: f32) -> Vec<f32> {
+    if count < 2 {
+        return vec![direction; count];
+    }
+
+    let step = spread / (count - 1) as f32;
+
+    (0..count)
+        .map(|i| direction - spread / 2.0 + step * i as f32)
+        .collect()
+}
+
+fn ring(start: f32, count: usize) -> Vec<f32> {
+    (0..count)
+        .map(|i| start + TAU * i as f32 / count as f32)
+        .collect()
+}
+
+#[derive(Component)]
+pub struct EnemyBullet;
+
+/// The emitter a bullet came from, so it can keep count of its live bullets.
+#[derive(Component)]
+struct EmittedBy(Entity);
+
+/// Eases a bullet's speed from `start` to `end` over `duration`.
+#[derive(Component)]
+struct SpeedCurve {
+    direction: Vec2,
+    start: f32,
+    end: f32,
+    duration: f32,
+    elapsed: f32,
+}
+
+pub struct EmitterPlugin;
+
+impl Plugin for EmitterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (fire_emitters, update_speed_curves).in_set(GameplaySet),
+        );
+    }
+}
+
+fn fire_emitters(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    mut query: Query<(Entity, &mut Emitter, &Transform, Option<&Parent>)>,
+    query_parent: Query<&Transform>,
+    query_player: Query<&Transform, With<Player>>,
+    query_bullet: Query<&EmittedBy>,
+) {
+    let period = time.period.as_secs_f32();
+
+    let mut live = HashMap::new();
+    for emitted_by in &query_bullet {
+        *live.entry(emitted_by.0).or_insert(0) += 1;
+    }
+
+    let player = query_player
+        .get_single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+
+    for (entity, mut emitter, transform, parent) in &mut query {
+        if !emitter.timer.tick(time.period).just_finished() {
+            continue;
+        }
+
+        //Emitters on child entities, like boss turrets, are placed relative to their parent
+        let position = match parent.and_then(|parent| query_parent.get(parent.get()).ok()) {
+            Some(transform_parent) => transform_parent.mul_transform(*transform).translation,
+            None => transform.translation,
+        }
+        .truncate();
+        let aim = match player {
+            Some(player) if player != position => {
+                let to_player = player - position;
+
+                to_player.y.atan2(to_player.x)
+            }
+            _ => down(),
+        };
+
+        let mut remaining = emitter
+            .def
+            .max_bullets
+            .saturating_sub(live.get(&entity).copied().unwrap_or(0));
+
+        for angle in emitter.directions(aim) {
+            if remaining == 0 {
+                break;
+            }
+            remaining -= 1;
+
+            spawn_enemy_bullet(
+                &mut commands,
+                entity,
+                position,
+                Vec2::from_angle(angle),
+                &emitter.def.bullet,
+                period,
+            );
+        }
+
+        emitter.volleys += 1;
+    }
+}
+
+fn spawn_enemy_bullet(
+    commands: &mut Commands,
+    emitter: Entity,
+    position: Vec2,
+    direction: Vec2,
+    bullet: &BulletDef,
+    period: f32,
+) {
+    let mut entity = commands.spawn((
+        EnemyBullet,
+        EmittedBy(emitter),
+        AutoDespawn,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgb(1.0, 0.3, 0.3),
+                custom_size: Some(Vec2::splat(3.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(position.x, position.y, 1.0),
+            ..default()
+        },
+        AABB {
+            half_size: Vec2::splat(1.5),
+        },
+        Movement {
+            acceleration: Vec2::ZERO,
+            velocity: direction * bullet.speed * period,
+            damping: 0.0,
+            max_speed: f32::INFINITY,
+        },
+        ContactDamage(bullet.damage),
+        Lifetime::from_seconds(bullet.lifetime),
+    ));
+
+    if let Some(end) = bullet.end_speed {
+        entity.insert(SpeedCurve {
+            direction,
+            start: bullet.speed,
+            end,
+            duration: bullet.ramp,
+            elapsed: 0.0,
+        });
+    }
+}
+
+fn update_speed_curves(time: Res<FixedTime>, mut query: Query<(&mut SpeedCurve, &mut Movement)>) {
+    let period = time.period.as_secs_f32();
+
+    for (mut curve, mut movement) in &mut query {
+        curve.elapsed += period;
+
+        let u = if curve.duration > 0.0 {
+            (curve.elapsed / curve.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let speed = curve.start + (curve.end - curve.start) * u;
+
+        movement.velocity = curve.direction * speed * period;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    const TICK: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+    fn emitter(pattern: EmitterPattern, cooldown: f32) -> Emitter {
+        Emitter::new(
+            EmitterDef {
+                pattern,
+                cooldown,
+                bullet: BulletDef::default(),
+                max_bullets: default_max_bullets(),
+            },
+            TICK,
+        )
+    }
+
+    fn assert_angles(angles: &[f32], expected: &[f32]) {
+        assert_eq!(angles.len(), expected.len());
+        for (angle, expected) in angles.iter().zip(expected) {
+            assert!(
+                (angle - expected).abs() < 1e-5,
+                "{angles:?} is not {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn fan_is_centered_on_its_direction() {
+        assert_angles(&fan(1.0, 3, 0.5), &[0.75, 1.0, 1.25]);
+        assert_angles(&fan(1.0, 2, 0.5), &[0.75, 1.25]);
+        assert_angles(&fan(1.0, 1, 0.5), &[1.0]);
+        assert!(fan(1.0, 0, 0.5).is_empty());
+    }
+
+    #[test]
+    fn ring_covers_the_full_turn() {
+        assert_angles(&ring(0.0, 4), &[0.0, PI / 2.0, PI, 3.0 * PI / 2.0]);
+        assert_angles(&ring(0.5, 2), &[0.5, 0.5 + PI]);
+        assert!(ring(0.0, 0).is_empty());
+    }
+
+    #[test]
+    fn aimed_follows_the_player() {
+        let aimed = emitter(
+            EmitterPattern::Aimed {
+                count: 3,
+                spread: 0.2,
+            },
+            1.0,
+        );
+
+        assert_angles(&aimed.directions(2.0), &[1.9, 2.0, 2.1]);
+    }
+
+    #[test]
+    fn spiral_turns_every_volley() {
+        let mut spiral = emitter(
+            EmitterPattern::Spiral {
+                count: 2,
+                turn: 0.3,
+            },
+            1.0,
+        );
+
+        assert_angles(&spiral.directions(0.0), &[0.0, PI]);
+
+        spiral.volleys = 2;
+        assert_angles(&spiral.directions(0.0), &[0.6, 0.6 + PI]);
+    }
+
+    #[test]
+    fn wave_sweeps_around_down() {
+        let mut wave = emitter(
+            EmitterPattern::Wave {
+                count: 1,
+                spread: 0.0,
+                sweep: 0.5,
+                frequency: 0.25,
+            },
+            1.0,
+        );
+
+        assert_angles(&wave.directions(0.0), &[down()]);
+
+        //A quarter of the way through the sweep, after one second
+        wave.volleys = 1;
+        assert_angles(&wave.directions(0.0), &[down() + 0.5]);
+    }
+
+    #[test]
+    fn cooldown_is_at_least_one_tick() {
+        let cooldown = |seconds| {
+            emitter(EmitterPattern::Radial { count: 1 }, seconds)
+                .timer
+                .duration()
+        };
+
+        assert_eq!(cooldown(0.0), TICK);
+        assert_eq!(cooldown(-1.0), TICK);
+        assert_eq!(cooldown(0.5), Duration::from_secs_f32(0.5));
+    }
+}
@@ -0,0 +1,185 @@
+//! Player health and lives. Anything with `ContactDamage` hurts the player on touch unless a
+//! `Shield` absorbs it, after which the ship blinks and cannot be hurt again for a short while.
+//! Losing all health costs a life, and losing the last life ends the run.
+
+use bevy::prelude::*;
+use sepax::prelude::sat_overlap;
+
+use crate::state::{GameState, GameplaySet};
+use crate::{hitbox, Player, AABB};
+
+/// How long the player cannot be hurt after a hit.
+const INVULNERABILITY: f32 = 1.5;
+/// How long the ship stays visible or hidden while blinking.
+const BLINK_PERIOD: f32 = 0.1;
+
+/// The health of the player, or of an enemy which takes more than one hit.
+#[derive(Component, Clone, Copy)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct Lives(pub u32);
+
+/// The damage dealt to the player on contact. The entity is destroyed when it hits, unless it
+/// is `Persistent`.
+#[derive(Component, Clone, Copy)]
+pub struct ContactDamage(pub f32);
+
+/// Keeps an entity with `ContactDamage` alive after it hits the player.
+#[derive(Component)]
+pub struct Persistent;
+
+/// Absorbs the next hit the player takes.
+#[derive(Component)]
+pub struct Shield;
+
+#[derive(Component)]
+pub struct Invulnerable {
+    timer: Timer,
+}
+
+impl Invulnerable {
+    fn from_seconds(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+/// Sent every time the player takes damage.
+#[derive(Event, Clone, Copy)]
+pub struct PlayerHit {
+    pub damage: f32,
+    pub health: f32,
+    pub lives: u32,
+}
+
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlayerHit>()
+            .add_systems(
+                FixedUpdate,
+                (update_invulnerability, damage_player)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .after(crate::check_collisions),
+            )
+            .add_systems(Update, blink_invulnerable);
+    }
+}
+
+fn damage_player(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut player_hit: EventWriter<PlayerHit>,
+    mut query_player: Query<
+        (
+            Entity,
+            &mut Health,
+            &mut Lives,
+            &AABB,
+            &Transform,
+            Option<&Shield>,
+        ),
+        (With<Player>, Without<Invulnerable>),
+    >,
+    query_damage: Query<(
+        Entity,
+        &ContactDamage,
+        &AABB,
+        &Transform,
+        Option<&Parent>,
+        Option<&Persistent>,
+    )>,
+    query_parent: Query<&Transform>,
+) {
+    for (entity, mut health, mut lives, aabb, transform, shield) in &mut query_player {
+        let player = hitbox(transform, aabb);
+
+        for (entity_damage, damage, aabb_damage, transform_damage, parent, persistent) in
+            &query_damage
+        {
+            //Children, like boss parts, are placed relative to their parent
+            let transform_damage =
+                match parent.and_then(|parent| query_parent.get(parent.get()).ok()) {
+                    Some(transform_parent) => transform_parent.mul_transform(*transform_damage),
+                    None => *transform_damage,
+                };
+
+            if !sat_overlap(&player, &hitbox(&transform_damage, aabb_damage)) {
+                continue;
+            }
+
+            if persistent.is_none() {
+                commands.entity(entity_damage).despawn_recursive();
+            }
+
+            if shield.is_some() {
+                commands
+                    .entity(entity)
+                    .remove::<Shield>()
+                    .insert(Invulnerable::from_seconds(INVULNERABILITY));
+                break;
+            }
+
+            health.current -= damage.0;
+            if health.current <= 0.0 {
+                lives.0 = lives.0.saturating_sub(1);
+                health.current = health.max;
+            }
+
+            player_hit.send(PlayerHit {
+                damage: damage.0,
+                health: health.current,
+                lives: lives.0,
+            });
+
+            if lives.0 == 0 {
+                next_state.set(GameState::GameOver);
+            } else {
+                commands
+                    .entity(entity)
+                    .insert(Invulnerable::from_seconds(INVULNERABILITY));
+            }
+
+            //One hit per tick, the rest are absorbed by the invulnerability
+            break;
+        }
+    }
+}
+
+fn update_invulnerability(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    mut query: Query<(Entity, &mut Invulnerable, &mut Visibility)>,
+) {
+    for (entity, mut invulnerable, mut visibility) in &mut query {
+        if invulnerable.timer.tick(time.period).finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+fn blink_invulnerable(mut query: Query<(&Invulnerable, &mut Visibility)>) {
+    for (invulnerable, mut visibility) in &mut query {
+        let blink = (invulnerable.timer.elapsed_secs() / BLINK_PERIOD) as u32;
+
+        *visibility = if blink % 2 == 0 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
@@ -0,0 +1,93 @@
+//! Smooth rendering between fixed simulation ticks.
+//!
+//! The simulation moves entities in `FixedUpdate`, which can run zero or several times per
+//! frame. Rendering the simulated `Transform` directly makes moving sprites stutter whenever
+//! the frame rate and tick rate differ, so each frame the rendered `Transform` is blended
+//! between the previous and current tick, and the simulated one is put back before the next
+//! tick runs.
+
+use bevy::prelude::*;
+
+use crate::Movement;
+
+/// Sets bracketing the fixed-tick simulation. Simulation systems that read or write
+/// `Transform` must run after `Restore` and before `Record`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InterpolationSet {
+    Restore,
+    Record,
+}
+
+/// The simulated transform of an entity at the previous and current fixed tick. Added
+/// automatically to every entity with `Movement`.
+#[derive(Component, Clone, Copy)]
+pub struct Interpolated {
+    previous: Transform,
+    current: Transform,
+}
+
+impl Interpolated {
+    fn new(transform: Transform) -> Self {
+        Self {
+            previous: transform,
+            current: transform,
+        }
+    }
+}
+
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            FixedUpdate,
+            (InterpolationSet::Restore, InterpolationSet::Record).chain(),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                restore_transforms.in_set(InterpolationSet::Restore),
+                (record_transforms, start_interpolation).in_set(InterpolationSet::Record),
+            ),
+        )
+        .add_systems(Update, interpolate_transforms);
+    }
+}
+
+fn start_interpolation(
+    mut commands: Commands,
+    query: Query<(Entity, &Transform), (With<Movement>, Without<Interpolated>)>,
+) {
+    for (entity, transform) in &query {
+        commands
+            .entity(entity)
+            .insert(Interpolated::new(*transform));
+    }
+}
+
+/// Put back the simulated transform, replacing the one blended for rendering.
+fn restore_transforms(mut query: Query<(&mut Interpolated, &mut Transform)>) {
+    for (mut interpolated, mut transform) in &mut query {
+        *transform = interpolated.current;
+        interpolated.previous = interpolated.current;
+    }
+}
+
+fn record_transforms(mut query: Query<(&mut Interpolated, &Transform)>) {
+    for (mut interpolated, transform) in &mut query {
+        interpolated.current = *transform;
+    }
+}
+
+fn interpolate_transforms(time: Res<FixedTime>, mut query: Query<(&Interpolated, &mut Transform)>) {
+    let alpha = (time.accumulated().as_secs_f32() / time.period.as_secs_f32()).clamp(0.0, 1.0);
+
+    for (interpolated, mut transform) in &mut query {
+        let previous = interpolated.previous;
+        let current = interpolated.current;
+
+        transform.translation = previous.translation.lerp(current.translation, alpha);
+        transform.rotation = previous.rotation.slerp(current.rotation, alpha);
+        transform.scale = previous.scale.lerp(current.scale, alpha);
+    }
+}
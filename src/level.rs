@@ -0,0 +1,405 @@
+//! Levels described as timed waves of enemies in `.level.ron` asset files, so stages can be
+//! built without touching the code.
+//!
+//! Each wave starts `delay` seconds after the previous one and spawns `count` enemies of one
+//! kind, `interval` seconds apart, placed in a formation around `position`. Enemies move
+//! according to `movement`, or follow a scripted `pattern` when one is given, and can carry
+//! an `emitter` to shoot at the player. Once every wave has spawned, an optional `boss` comes
+//! in, and the level only repeats after it is destroyed:
+//!
+//! ```ron
+//! (
+//!     waves: [
+//!         (
+//!             delay: 2.0,
+//!             enemy: Scout,
+//!             count: 5,
+//!             formation: Line(spacing: 40.0),
+//!             position: (0.0, 200.0),
+//!             movement: (velocity: (0.0, -1.0), acceleration: (0.0, -0.1), max_speed: 10.0),
+//!         ),
+//!         (
+//!             delay: 4.0,
+//!             enemy: Heavy,
+//!             count: 1,
+//!             lifetime: 10.0,
+//!             pattern: Some(Sine(speed: 30.0, amplitude: 80.0, frequency: 0.5)),
+//!             emitter: Some((pattern: Radial(count: 12), cooldown: 1.5)),
+//!         ),
+//!     ],
+//!     boss: Some((path: "bosses/warden.boss.ron", delay: 5.0)),
+//!     repeat: true,
+//! )
+//! ```
+
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use serde::Deserialize;
+
+use crate::boss::{Boss, PendingBoss};
+use crate::emitter::{Emitter, EmitterDef};
+use crate::health::Health;
+use crate::pattern::{MovementPattern, Pattern};
+use crate::ron_asset::RonLoader;
+use crate::state::{GameState, GameplaySet};
+use crate::{Enemy, EnemyBundle, EnemyKind, Lifetime, Movement, AABB, GAME_HEIGHT};
+
+const FIRST_LEVEL: &str = "levels/stage_01.level.ron";
+
+#[derive(Deserialize, TypeUuid, TypePath)]
+#[uuid = "5b7a9c1e-3f0d-4e62-9a8b-2c4d6e8f1a3b"]
+pub struct Level {
+    pub waves: Vec<Wave>,
+    #[serde(default)]
+    pub boss: Option<BossEntry>,
+    /// Start over from the first wave once the last one has spawned.
+    #[serde(default)]
+    pub repeat: bool,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Wave {
+    /// Seconds between the start of the previous wave and this one.
+    #[serde(default)]
+    pub delay: f32,
+    pub enemy: EnemyKind,
+    pub count: usize,
+    #[serde(default)]
+    pub formation: Formation,
+    /// The center of the formation. Defaults to just above the middle of the screen.
+    #[serde(default = "default_position")]
+    pub position: (f32, f32),
+    /// Seconds between two enemies of the wave, or zero to spawn them all at once.
+    #[serde(default)]
+    pub interval: f32,
+    /// Seconds before each enemy despawns.
+    #[serde(default = "default_lifetime")]
+    pub lifetime: f32,
+    /// Per-tick movement. Enemies drift straight down when it is left out.
+    #[serde(default)]
+    pub movement: MovementDef,
+    /// Scripted movement which replaces `movement` when given.
+    #[serde(default)]
+    pub pattern: Option<Pattern>,
+    /// A weapon every enemy of the wave carries.
+    #[serde(default)]
+    pub emitter: Option<EmitterDef>,
+}
+
+fn default_position() -> (f32, f32) {
+    (0.0, GAME_HEIGHT / 2.0 + 16.0)
+}
+
+#[derive(Deserialize, Clone)]
+pub struct BossEntry {
+    /// The boss's `.boss.ron` file.
+    pub path: String,
+    /// Seconds between the start of the last wave and the boss.
+    #[serde(default)]
+    pub delay: f32,
+    #[serde(default = "default_boss_position")]
+    pub position: (f32, f32),
+}
+
+fn default_boss_position() -> (f32, f32) {
+    (0.0, GAME_HEIGHT / 2.0 + 32.0)
+}
+
+fn default_lifetime() -> f32 {
+    5.0
+}
+
+/// Where each enemy of a wave is placed relative to the wave's position.
+#[derive(Deserialize, Clone, Copy, Default)]
+pub enum Formation {
+    /// Every enemy at the wave's position, best combined with an interval.
+    #[default]
+    Single,
+    /// A horizontal row centered on the position.
+    Line { spacing: f32 },
+    /// A vertical column going up from the position.
+    Column { spacing: f32 },
+    /// A V shape with its tip at the position.
+    V { spacing: f32 },
+    /// A circle around the position.
+    Circle { radius: f32 },
+}
+
+impl Formation {
+    fn offset(&self, index: usize, count: usize) -> Vec2 {
+        let centered = index as f32 - (count as f32 - 1.0) / 2.0;
+
+        match *self {
+            Formation::Single => Vec2::ZERO,
+            Formation::Line { spacing } => Vec2::new(centered * spacing, 0.0),
+            Formation::Column { spacing } => Vec2::new(0.0, index as f32 * spacing),
+            Formation::V { spacing } => Vec2::new(centered * spacing, centered.abs() * spacing),
+            Formation::Circle { radius } => {
+                let angle = TAU * index as f32 / count as f32;
+
+                Vec2::new(angle.cos(), angle.sin()) * radius
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub struct MovementDef {
+    #[serde(default)]
+    pub velocity: (f32, f32),
+    #[serde(default)]
+    pub acceleration: (f32, f32),
+    #[serde(default)]
+    pub damping: f32,
+    #[serde(default = "default_max_speed")]
+    pub max_speed: f32,
+}
+
+impl Default for MovementDef {
+    fn default() -> Self {
+        Self {
+            velocity: (0.0, -1.0),
+            acceleration: (0.0, 0.0),
+            damping: 0.0,
+            max_speed: default_max_speed(),
+        }
+    }
+}
+
+fn default_max_speed() -> f32 {
+    10.0
+}
+
+impl From<MovementDef> for Movement {
+    fn from(movement: MovementDef) -> Self {
+        Self {
+            acceleration: movement.acceleration.into(),
+            velocity: movement.velocity.into(),
+            damping: movement.damping,
+            max_speed: movement.max_speed,
+        }
+    }
+}
+
+/// How far the current run has got through its level.
+#[derive(Resource)]
+pub struct LevelProgress {
+    level: Handle<Level>,
+    elapsed: f32,
+    wave: usize,
+    wave_start: f32,
+    spawned: usize,
+    boss_spawned: bool,
+}
+
+impl LevelProgress {
+    fn new(level: Handle<Level>) -> Self {
+        Self {
+            level,
+            elapsed: 0.0,
+            wave: 0,
+            wave_start: 0.0,
+            spawned: 0,
+            boss_spawned: false,
+        }
+    }
+}
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Level>()
+            .add_asset_loader(RonLoader::<Level>::new(&["level.ron"]))
+            .add_systems(OnExit(GameState::Title), start_level)
+            .add_systems(FixedUpdate, spawn_waves.in_set(GameplaySet));
+    }
+}
+
+fn start_level(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LevelProgress::new(asset_server.load(FIRST_LEVEL)));
+}
+
+fn spawn_waves(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    asset_server: Res<AssetServer>,
+    levels: Res<Assets<Level>>,
+    progress: Option<ResMut<LevelProgress>>,
+    query_boss: Query<(), Or<(With<Boss>, With<PendingBoss>)>>,
+) {
+    let Some(mut progress) = progress else {
+        return;
+    };
+    //Time only starts counting once the level has loaded
+    let Some(level) = levels.get(&progress.level) else {
+        return;
+    };
+
+    progress.elapsed += time.period.as_secs_f32();
+
+    while let Some(wave) = level.waves.get(progress.wave) {
+        let start = progress.wave_start + wave.delay;
+
+        while progress.spawned < wave.count
+            && progress.elapsed >= start + progress.spawned as f32 * wave.interval
+        {
+            spawn_enemy(
+                &mut commands,
+                &asset_server,
+                wave,
+                progress.spawned,
+                time.period,
+            );
+            progress.spawned += 1;
+        }
+
+        if progress.spawned < wave.count {
+            return;
+        }
+
+        progress.wave_start = start;
+        progress.wave += 1;
+        progress.spawned = 0;
+    }
+
+    if let Some(boss) = &level.boss {
+        if !progress.boss_spawned {
+            let start = progress.wave_start + boss.delay;
+            if progress.elapsed < start {
+                return;
+            }
+
+            commands.spawn(PendingBoss::new(
+                asset_server.load(boss.path.as_str()),
+                boss.position.into(),
+            ));
+            progress.boss_spawned = true;
+            progress.wave_start = start;
+            //The boss only shows up in the query once the commands are applied
+            return;
+        }
+
+        if !query_boss.is_empty() {
+            return;
+        }
+
+        //The next loop is timed from the boss's defeat
+        progress.wave_start = progress.elapsed;
+    }
+
+    //Pick the loop up on the next tick, in case no wave takes any time
+    if level.repeat {
+        progress.wave = 0;
+        progress.boss_spawned = false;
+    }
+}
+
+fn spawn_enemy(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    wave: &Wave,
+    index: usize,
+    period: Duration,
+) {
+    let position = Vec2::from(wave.position) + wave.formation.offset(index, wave.count);
+
+    let mut enemy = commands.spawn(EnemyBundle {
+        sprite: SpriteBundle {
+            texture: asset_server.load(wave.enemy.texture()),
+            transform: Transform::from_xyz(position.x, position.y, 1.0),
+            ..default()
+        },
+        movement: wave.movement.into(),
+        aabb: AABB {
+            half_size: wave.enemy.half_size(),
+        },
+        enemy: Enemy { kind: wave.enemy },
+        health: Health::new(wave.enemy.health()),
+        lifetime: Lifetime::from_seconds(wave.lifetime),
+        ..default()
+    });
+
+    if let Some(pattern) = &wave.pattern {
+        enemy.insert(MovementPattern::new(pattern.clone(), position));
+    }
+
+    if let Some(emitter) = &wave.emitter {
+        enemy.insert(Emitter::new(emitter.clone(), period));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets(formation: Formation, count: usize) -> Vec<Vec2> {
+        (0..count)
+            .map(|index| formation.offset(index, count))
+            .collect()
+    }
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-4, "{a} is not {b}");
+    }
+
+    #[test]
+    fn single_stacks_on_the_position() {
+        assert_eq!(offsets(Formation::Single, 3), vec![Vec2::ZERO; 3]);
+    }
+
+    #[test]
+    fn line_is_centered() {
+        let line = offsets(Formation::Line { spacing: 10.0 }, 3);
+
+        assert_eq!(
+            line,
+            vec![Vec2::new(-10.0, 0.0), Vec2::ZERO, Vec2::new(10.0, 0.0)]
+        );
+        assert_eq!(
+            offsets(Formation::Line { spacing: 10.0 }, 1),
+            vec![Vec2::ZERO]
+        );
+    }
+
+    #[test]
+    fn column_goes_up() {
+        let column = offsets(Formation::Column { spacing: 5.0 }, 3);
+
+        assert_eq!(
+            column,
+            vec![Vec2::ZERO, Vec2::new(0.0, 5.0), Vec2::new(0.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn v_has_its_tip_at_the_position() {
+        let v = offsets(Formation::V { spacing: 10.0 }, 5);
+
+        assert_eq!(v[2], Vec2::ZERO);
+        assert_eq!(v[0], Vec2::new(-20.0, 20.0));
+        assert_eq!(v[4], Vec2::new(20.0, 20.0));
+        assert_eq!(v[1].y, v[3].y);
+    }
+
+    #[test]
+    fn circle_spreads_evenly() {
+        let circle = offsets(Formation::Circle { radius: 30.0 }, 4);
+
+        assert_near(circle[0], Vec2::new(30.0, 0.0));
+        assert_near(circle[1], Vec2::new(0.0, 30.0));
+        assert_near(circle[2], Vec2::new(-30.0, 0.0));
+        assert_near(circle[3], Vec2::new(0.0, -30.0));
+    }
+
+    #[test]
+    fn default_movement_moves() {
+        let movement = Movement::from(MovementDef::default());
+
+        assert!(movement.velocity.y < 0.0);
+        assert!(movement.max_speed >= movement.velocity.length());
+    }
+}
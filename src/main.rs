@@ -1,16 +1,23 @@
 #![allow(unused_parens)]
 
-use sepax::Sepax;
-use std::f32::consts::PI;
-
-use rand::{thread_rng, Rng};
-
 use bevy::{
     diagnostic::{Diagnostics, FrameTimeDiagnosticsPlugin},
     prelude::*,
 };
 use bevy_pixel_camera::{PixelCameraBundle, PixelCameraPlugin};
+use boss::BossPlugin;
+use emitter::EmitterPlugin;
+use health::{ContactDamage, Health, HealthPlugin, Lives};
+use interpolation::InterpolationPlugin;
+use level::LevelPlugin;
+use mirror::{reflect_bullets, spawn_mirror_spawner, spawn_mirrors};
+use pattern::{update_patterns, MovementPattern};
+use pickup::{Magnet, PickupPlugin};
+use score::{EnemyKilled, Score, ScorePlugin};
 use sepax::prelude::*;
+use serde::Deserialize;
+use state::{GameState, GameplaySet, StatePlugin};
+use weapon::{fire_weapons, Weapon, WeaponDef, WeaponLibrary, WeaponPlugin};
 
 const WINDOW_WIDTH: f32 = 1024.0;
 const WINDOW_HEIGHT: f32 = 768.0;
@@ -20,14 +27,22 @@ const SCALE: i32 = 2;
 const GAME_WIDTH: f32 = WINDOW_WIDTH / SCALE as f32;
 const GAME_HEIGHT: f32 = WINDOW_HEIGHT / SCALE as f32;
 
-const BULLET_SPEED: f32 = 3.0;
-
-pub mod sepax;
+mod boss;
+mod emitter;
+mod health;
+mod interpolation;
+mod level;
+mod mirror;
+mod pattern;
+mod pickup;
+mod ron_asset;
+mod score;
+mod state;
+mod weapon;
 
 fn main() {
     App::new()
         .insert_resource(EntityCount::default())
-        .insert_resource(Score(0))
         .add_plugins(
             DefaultPlugins
                 .set(ImagePlugin::default_nearest())
@@ -43,7 +58,17 @@ fn main() {
         )
         .add_plugin(FrameTimeDiagnosticsPlugin)
         .add_plugin(PixelCameraPlugin)
+        .add_plugin(InterpolationPlugin)
+        .add_plugin(StatePlugin)
+        .add_plugin(ScorePlugin)
+        .add_plugin(HealthPlugin)
+        .add_plugin(LevelPlugin)
+        .add_plugin(EmitterPlugin)
+        .add_plugin(WeaponPlugin)
+        .add_plugin(PickupPlugin)
+        .add_plugin(BossPlugin)
         .add_systems(Startup, (setup))
+        .add_systems(OnExit(GameState::Title), spawn_gameplay)
         .add_systems(Update, (update_debug_text))
         .add_systems(
             FixedUpdate,
@@ -51,67 +76,64 @@ fn main() {
                 (
                     update_player_movement,
                     update_movement,
+                    update_patterns,
                     clamp_inside_world,
-                    shoot,
+                    fire_weapons,
+                    reflect_bullets,
                     check_collisions,
                 )
                     .chain(),
-                spawn_enemies,
                 update_lifetimes,
                 despawn_outside_world,
                 spawn_mirrors,
-            ),
+            )
+                .in_set(GameplaySet),
         )
         .run();
 }
 
-fn spawn_mirrors(
-    mut commands: Commands,
-    time: Res<FixedTime>,
-    mut query: Query<(&mut MirrorSpawner, &GlobalTransform)>,
-) {
-    for (mut mirror_spawner, transform) in &mut query {
-        if mirror_spawner.timer.tick(time.period).finished() {
-            commands.spawn(MirrorBundle {
-                aabb: AABB { position },
-                lifetime: Lifetime::from_seconds(10.0),
-                movement: Movement {
-                    velocity: Vec2::new(0.0, 1.0),
-                    max_speed: 1.0,
-                    ..default()
-                },
-                sprite: SpriteBundle {
-                    transform: //Transform::default(),
-                    transform
-                        .compute_transform()
-                        .with_rotation(Quat::from_rotation_z(mirror_spawner.angle)),
-                    sprite: Sprite {
-                        color: Color::WHITE,
-                        custom_size: Some(Vec2::new(16.0, 2.0)),
-                        ..default()
-                    },
-                    ..default()
-                },
-                ..default()
-            });
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug, Deserialize)]
+enum EnemyKind {
+    #[default]
+    Scout,
+    Heavy,
+}
+
+impl EnemyKind {
+    /// The points awarded for destroying an enemy of this kind, before multipliers.
+    fn points(&self) -> usize {
+        match self {
+            EnemyKind::Scout => 100,
+            EnemyKind::Heavy => 250,
         }
     }
-}
 
-#[derive(Component, Default)]
-struct Mirror;
+    /// The total damage an enemy of this kind takes before it is destroyed.
+    fn health(&self) -> f32 {
+        match self {
+            EnemyKind::Scout => 1.0,
+            EnemyKind::Heavy => 3.0,
+        }
+    }
 
-#[derive(Bundle, Default)]
-struct MirrorBundle {
-    sprite: SpriteBundle,
-    movement: Movement,
-    aabb: AABB,
-    mirror: Mirror,
-    lifetime: Lifetime,
+    fn texture(&self) -> &'static str {
+        match self {
+            EnemyKind::Scout => "enemy_01.png",
+            EnemyKind::Heavy => "enemy_02.png",
+        }
+    }
+
+    fn half_size(&self) -> Vec2 {
+        match self {
+            EnemyKind::Scout | EnemyKind::Heavy => Vec2::splat(16.0),
+        }
+    }
 }
 
 #[derive(Component, Default)]
-struct Enemy;
+struct Enemy {
+    kind: EnemyKind,
+}
 
 #[derive(Bundle)]
 struct EnemyBundle {
@@ -119,22 +141,11 @@ struct EnemyBundle {
     movement: Movement,
     aabb: AABB,
     enemy: Enemy,
+    health: Health,
+    contact_damage: ContactDamage,
     lifetime: Lifetime,
 }
 
-#[derive(Component)]
-struct MirrorSpawner {
-    timer: Timer,
-    angle: f32,
-}
-
-#[derive(Bundle)]
-struct MirrorSpawnerBundle {
-    mirror_spawner: MirrorSpawner,
-    #[bundle]
-    transform_bundle: TransformBundle,
-}
-
 impl Default for EnemyBundle {
     fn default() -> Self {
         Self {
@@ -142,19 +153,13 @@ impl Default for EnemyBundle {
             movement: Movement::default(),
             aabb: AABB::default(),
             enemy: Enemy::default(),
+            health: Health::new(EnemyKind::default().health()),
+            contact_damage: ContactDamage(1.0),
             lifetime: Lifetime::from_seconds(5.0),
         }
     }
 }
 
-#[derive(Component)]
-struct EnemySpawner {
-    timer: Timer,
-    texture: Handle<Image>,
-    movement: Movement,
-    aabb: AABB,
-}
-
 #[derive(Component, Default)]
 struct Lifetime {
     timer: Timer,
@@ -168,54 +173,23 @@ impl Lifetime {
     }
 }
 
-fn spawn_enemies(
-    time: Res<FixedTime>,
-    mut commands: Commands,
-    mut query: Query<(&mut EnemySpawner)>,
-) {
-    for (mut enemy_spawner) in &mut query {
-        if enemy_spawner.timer.tick(time.period).finished() {
-            let mut rng = thread_rng();
-            let x = (rng.gen::<f32>() * GAME_WIDTH - GAME_WIDTH / 2.0) * 0.95;
-            let y = GAME_HEIGHT / 2.0;
-
-            commands.spawn(
-                (EnemyBundle {
-                    sprite: SpriteBundle {
-                        texture: enemy_spawner.texture.clone(),
-                        transform: Transform::from_xyz(x, y + 16., 1.0),
-                        ..default()
-                    },
-                    movement: enemy_spawner.movement,
-                    aabb: enemy_spawner.aabb,
-                    ..default()
-                }),
-            );
-        }
-    }
-}
-
 fn check_collisions(
     mut commands: Commands,
-    query_enemy: Query<(Entity, &AABB, &Transform), With<Enemy>>,
-    query_mirror: Query<(Entity, &AABB, &Transform), With<Mirror>>,
-    query_bullet: Query<(Entity, &AABB, &Transform), With<Bullet>>,
+    mut enemy_killed: EventWriter<EnemyKilled>,
+    mut query_enemy: Query<(Entity, &Enemy, &mut Health, &AABB, &Transform)>,
+    query_bullet: Query<(Entity, &Bullet, &AABB, &Transform, Option<&Damage>)>,
 ) {
-    for (entity, aabb, transform) in &query_bullet {
+    let mut killed = Vec::new();
+
+    for (entity, bullet, aabb, transform, damage) in &query_bullet {
         let position = transform.translation;
         let half_size = aabb.half_size;
 
-        for (entity_mirror, aabb_mirror, transform_mirror) in &query_mirror {
-            match sat_collision(aabb_mirror, aabb) {
-                (0., 0.) => {
-                    commands.entity(entity).despawn_recursive();
-                    commands.entity(entity_mirror).despawn_recursive();
-                }
-                col => {}
+        for (entity_enemy, enemy, mut health, aabb_enemy, transform_enemy) in &mut query_enemy {
+            if killed.contains(&entity_enemy) {
+                continue;
             }
-        }
 
-        for (entity_enemy, aabb_enemy, transform_enemy) in &query_enemy {
             let position_enemy = transform_enemy.translation;
             let half_size_enemy = aabb_enemy.half_size;
 
@@ -225,7 +199,21 @@ fn check_collisions(
                 && position.y - half_size.y < position_enemy.y + half_size_enemy.y
             {
                 commands.entity(entity).despawn_recursive();
+
+                health.current -= damage.map_or(1.0, |damage| damage.0);
+                if health.current > 0.0 {
+                    break;
+                }
+
                 commands.entity(entity_enemy).despawn_recursive();
+
+                killed.push(entity_enemy);
+                enemy_killed.send(EnemyKilled {
+                    kind: enemy.kind,
+                    position: position_enemy.truncate(),
+                    bounces: bullet.bounces,
+                });
+                break;
             }
         }
     }
@@ -264,38 +252,24 @@ fn despawn_outside_world(
     }
 }
 
-#[derive(Component)]
-struct Bullet;
-
-fn shoot(
-    keys: Res<Input<KeyCode>>,
-    mut commands: Commands,
-    mut query: Query<(&Transform, &AABB), With<Player>>,
-) {
-    if keys.pressed(KeyCode::Space) {
-        for (transform, aabb) in &mut query {
-            spawn_bullet(transform, aabb, &mut commands, Direction::Left);
-            spawn_bullet(transform, aabb, &mut commands, Direction::Right);
-        }
-    }
+#[derive(Component, Default)]
+struct Bullet {
+    /// The number of mirrors the bullet has bounced off.
+    bounces: u32,
 }
 
+/// The damage a bullet deals to the enemy or boss part it hits. Bullets without it deal 1.
+#[derive(Component, Clone, Copy)]
+struct Damage(f32);
+
 enum Direction {
     Left,
     Right,
 }
 
-fn spawn_bullet(transform: &Transform, aabb: &AABB, commands: &mut Commands, direction: Direction) {
-    let position = transform.translation;
-    let half_size = aabb.half_size;
-
-    let direction_component = match direction {
-        Direction::Left => -1.0,
-        Direction::Right => 1.0,
-    };
-
+fn spawn_bullet(commands: &mut Commands, position: Vec2, velocity: Vec2, damage: f32) {
     commands.spawn((
-        Bullet,
+        Bullet::default(),
         AutoDespawn,
         SpriteBundle {
             sprite: Sprite {
@@ -303,11 +277,7 @@ fn spawn_bullet(transform: &Transform, aabb: &AABB, commands: &mut Commands, dir
                 custom_size: Some(Vec2::splat(2.0)),
                 ..default()
             },
-            transform: Transform::from_xyz(
-                position.x + half_size.x * direction_component,
-                position.y,
-                1.0,
-            ),
+            transform: Transform::from_xyz(position.x, position.y, 1.0),
             ..default()
         },
         AABB {
@@ -315,10 +285,11 @@ fn spawn_bullet(transform: &Transform, aabb: &AABB, commands: &mut Commands, dir
         },
         Movement {
             acceleration: Vec2::ZERO,
-            velocity: Vec2::new(direction_component * BULLET_SPEED, 0.0),
+            velocity,
             damping: 0.0,
-            max_speed: 10.0,
+            max_speed: f32::INFINITY,
         },
+        Damage(damage),
         Lifetime::from_seconds(5.0),
     ));
 }
@@ -356,18 +327,32 @@ struct Movement {
     max_speed: f32,
 }
 
-#[derive(Resource)]
-struct Score(usize);
+/// The half extents of an entity's hitbox, centered on its translation and turned with its
+/// rotation.
+#[derive(Component, Default, Clone, Copy)]
+struct AABB {
+    half_size: Vec2,
+}
 
-fn setup(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-) {
+/// The hitbox of an entity in world space.
+fn hitbox(transform: &Transform, aabb: &AABB) -> Parallelogram {
+    let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+    let half_size = aabb.half_size;
+
+    Parallelogram::rectangle(
+        (-half_size.x, -half_size.y),
+        half_size.x * 2.0,
+        half_size.y * 2.0,
+    )
+    .transformed(&Transform2D::new(
+        (transform.translation.x, transform.translation.y),
+        angle,
+        1.0,
+    ))
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font = asset_server.load("fonts/FiraSans-Bold.ttf");
-    let ship_handle = asset_server.load("ship.png");
-    let ship_atlas = TextureAtlas::from_grid(ship_handle, Vec2::new(48.0, 32.0), 6, 1, None, None);
-    let ship_atlas_handle = texture_atlases.add(ship_atlas);
 
     commands.spawn((Camera, PixelCameraBundle::from_zoom(2)));
 
@@ -380,25 +365,6 @@ fn setup(
         },
     ));
 
-    commands.spawn((
-        Player,
-        SpriteSheetBundle {
-            texture_atlas: ship_atlas_handle,
-            sprite: TextureAtlasSprite::new(0),
-            transform: Transform::from_xyz(0.0, 32. - GAME_HEIGHT / 2., 1.0),
-            ..default()
-        },
-        AABB {
-            half_size: Vec2::splat(16.),
-        },
-        Movement {
-            acceleration: Vec2::ZERO,
-            velocity: Vec2::ZERO,
-            damping: 0.1,
-            max_speed: 2.,
-        },
-    ));
-
     commands.spawn((
         DebugText::new(),
         TextBundle::from_sections([
@@ -418,6 +384,14 @@ fn setup(
                     color: Color::WHITE,
                 },
             ),
+            TextSection::new(
+                "",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 12.0,
+                    color: Color::WHITE,
+                },
+            ),
         ])
         .with_style(Style {
             position_type: PositionType::Absolute,
@@ -426,66 +400,52 @@ fn setup(
             ..default()
         }),
     ));
+}
 
-    commands.spawn(EnemySpawner {
-        timer: Timer::from_seconds(1.0, TimerMode::Repeating),
-        texture: asset_server.load("enemy_01.png"),
-        movement: Movement {
-            acceleration: Vec2::new(0.0, -0.1),
-            velocity: Vec2::new(0.0, -1.0),
-            damping: 0.0,
-            max_speed: 10.0,
-        },
-        aabb: AABB {
-            half_size: Vec2::splat(16.0),
+fn spawn_gameplay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+) {
+    let ship_handle = asset_server.load("ship.png");
+    let ship_atlas = TextureAtlas::from_grid(ship_handle, Vec2::new(48.0, 32.0), 6, 1, None, None);
+    let ship_atlas_handle = texture_atlases.add(ship_atlas);
+
+    commands.spawn((
+        Player,
+        SpriteSheetBundle {
+            texture_atlas: ship_atlas_handle,
+            sprite: TextureAtlasSprite::new(0),
+            transform: Transform::from_xyz(0.0, 32. - GAME_HEIGHT / 2., 1.0),
+            ..default()
         },
-    });
-
-    commands.spawn(EnemySpawner {
-        timer: Timer::from_seconds(1.5, TimerMode::Repeating),
-        texture: asset_server.load("enemy_02.png"),
-        movement: Movement {
-            acceleration: Vec2::new(0.0, -0.1),
-            velocity: Vec2::new(0.0, -0.5),
-            damping: 0.0,
-            max_speed: 10.0,
+        AABB {
+            half_size: Vec2::splat(16.),
         },
-        aabb: AABB {
-            half_size: Vec2::splat(16.0),
+        Movement {
+            acceleration: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            damping: 0.1,
+            max_speed: 2.,
         },
-    });
+        Health::new(3.0),
+        Lives(3),
+        Weapon::new(0),
+        Magnet { radius: 32.0 },
+    ));
 
     spawn_mirror_spawner(&mut commands, Direction::Left);
     spawn_mirror_spawner(&mut commands, Direction::Right);
 }
 
-fn spawn_mirror_spawner(commands: &mut Commands, direction: Direction) {
-    let angle = match direction {
-        Direction::Left => -PI / 4.,
-        Direction::Right => PI / 4.,
-    };
-    let x = match direction {
-        Direction::Left => -GAME_WIDTH / 2. + 10.,
-        Direction::Right => GAME_WIDTH / 2. - 10.,
-    };
-    commands.spawn(MirrorSpawnerBundle {
-        mirror_spawner: MirrorSpawner {
-            timer: Timer::from_seconds(1., TimerMode::Repeating),
-            angle,
-        },
-        transform_bundle: TransformBundle::from_transform(Transform::from_xyz(
-            x,
-            -GAME_HEIGHT / 2. - 10.,
-            1.0,
-        )),
-    });
-}
-
 fn update_debug_text(
     time: Res<Time>,
     diagnostics: Res<Diagnostics>,
     mut query: Query<(&mut Text, &mut DebugText)>,
+    query_player: Query<(&Health, &Lives, &Weapon), With<Player>>,
     score: Res<Score>,
+    library: Res<WeaponLibrary>,
+    weapons: Res<Assets<WeaponDef>>,
 ) {
     for (mut text, mut debug_text) in &mut query {
         debug_text.timer.tick(time.delta());
@@ -497,6 +457,24 @@ fn update_debug_text(
             }
             let score = score.0;
             text.sections[1].value = format!("\nScore: {score}");
+            text.sections[2].value = match query_player.get_single() {
+                Ok((health, lives, weapon)) => {
+                    let name = library
+                        .weapons
+                        .get(weapon.weapon)
+                        .and_then(|handle| weapons.get(handle))
+                        .map_or("", |def| def.name.as_str());
+
+                    format!(
+                        "\nHealth: {}  Lives: {}\n{} Lv{}",
+                        health.current,
+                        lives.0,
+                        name,
+                        weapon.level + 1
+                    )
+                }
+                Err(_) => String::new(),
+            };
         }
     }
 }
@@ -527,7 +505,7 @@ fn update_player_movement(
     }
 }
 
-fn update_movement(mut query: Query<(&mut Movement, &mut Transform)>) {
+fn update_movement(mut query: Query<(&mut Movement, &mut Transform), Without<MovementPattern>>) {
     for (mut movement, mut transform) in &mut query {
         let acceleration = movement.acceleration;
         if acceleration.x != 0.0 || acceleration.y != 0.0 {
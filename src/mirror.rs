@@ -0,0 +1,152 @@
+//! Angled mirrors which drift up from the bottom of the screen and reflect the player's bullets.
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use sepax::prelude::sat_collision;
+
+use crate::{hitbox, Bullet, Damage, Direction, Lifetime, Movement, AABB, GAME_HEIGHT, GAME_WIDTH};
+
+const MIRROR_DAMAGE_MULTIPLIER: f32 = 1.5;
+
+#[derive(Component)]
+pub struct Mirror {
+    /// Multiplies the damage of a bullet every time it bounces off this mirror.
+    damage_multiplier: f32,
+}
+
+impl Default for Mirror {
+    fn default() -> Self {
+        Self {
+            damage_multiplier: MIRROR_DAMAGE_MULTIPLIER,
+        }
+    }
+}
+
+#[derive(Bundle, Default)]
+struct MirrorBundle {
+    sprite: SpriteBundle,
+    movement: Movement,
+    aabb: AABB,
+    mirror: Mirror,
+    lifetime: Lifetime,
+}
+
+#[derive(Component)]
+pub struct MirrorSpawner {
+    timer: Timer,
+    angle: f32,
+}
+
+#[derive(Bundle)]
+struct MirrorSpawnerBundle {
+    mirror_spawner: MirrorSpawner,
+    #[bundle]
+    transform_bundle: TransformBundle,
+}
+
+pub fn spawn_mirror_spawner(commands: &mut Commands, direction: Direction) {
+    let angle = match direction {
+        Direction::Left => -PI / 4.,
+        Direction::Right => PI / 4.,
+    };
+    let x = match direction {
+        Direction::Left => -GAME_WIDTH / 2. + 10.,
+        Direction::Right => GAME_WIDTH / 2. - 10.,
+    };
+    commands.spawn(MirrorSpawnerBundle {
+        mirror_spawner: MirrorSpawner {
+            timer: Timer::from_seconds(1., TimerMode::Repeating),
+            angle,
+        },
+        transform_bundle: TransformBundle::from_transform(Transform::from_xyz(
+            x,
+            -GAME_HEIGHT / 2. - 10.,
+            1.0,
+        )),
+    });
+}
+
+pub fn spawn_mirrors(
+    mut commands: Commands,
+    time: Res<FixedTime>,
+    mut query: Query<(&mut MirrorSpawner, &GlobalTransform)>,
+) {
+    for (mut mirror_spawner, transform) in &mut query {
+        if mirror_spawner.timer.tick(time.period).finished() {
+            commands.spawn(MirrorBundle {
+                aabb: AABB {
+                    half_size: Vec2::new(8.0, 1.0),
+                },
+                lifetime: Lifetime::from_seconds(10.0),
+                movement: Movement {
+                    velocity: Vec2::new(0.0, 1.0),
+                    max_speed: 1.0,
+                    ..default()
+                },
+                sprite: SpriteBundle {
+                    transform: transform
+                        .compute_transform()
+                        .with_rotation(Quat::from_rotation_z(mirror_spawner.angle)),
+                    sprite: Sprite {
+                        color: Color::WHITE,
+                        custom_size: Some(Vec2::new(16.0, 2.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            });
+        }
+    }
+}
+
+/// Pushes bullets out of any mirror they overlap and reflects their velocity about the
+/// mirror's surface normal. Bullets keep their lifetime, so they can bounce several times.
+pub fn reflect_bullets(
+    query_mirror: Query<(&Mirror, &Transform, &AABB)>,
+    mut query_bullet: Query<
+        (
+            &mut Bullet,
+            &mut Transform,
+            &mut Movement,
+            &AABB,
+            Option<&mut Damage>,
+        ),
+        Without<Mirror>,
+    >,
+) {
+    for (mut bullet, mut transform, mut movement, aabb, mut damage) in &mut query_bullet {
+        for (mirror, transform_mirror, aabb_mirror) in &query_mirror {
+            let (x, y) = sat_collision(
+                &hitbox(transform_mirror, aabb_mirror),
+                &hitbox(&transform, aabb),
+            );
+            let push = Vec2::new(x, y);
+
+            if push == Vec2::ZERO {
+                continue;
+            }
+
+            transform.translation.x += push.x;
+            transform.translation.y += push.y;
+
+            //The long side of the sprite lies along the mirror's local x axis. The push can run
+            //along that side when a bullet clips an end, so face the normal against the bullet
+            let mut normal = (transform_mirror.rotation * Vec3::Y).truncate();
+            if normal.dot(movement.velocity) > 0.0 {
+                normal = -normal;
+            }
+
+            let speed_into_mirror = movement.velocity.dot(normal);
+            if speed_into_mirror < 0.0 {
+                movement.velocity -= 2.0 * speed_into_mirror * normal;
+                bullet.bounces += 1;
+
+                if let Some(damage) = damage.as_mut() {
+                    damage.0 *= mirror.damage_multiplier;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Scripted movement for enemies. An entity with a `MovementPattern` follows it instead of
+//! integrating its `Movement`, whose velocity is still updated so other systems can read it.
+//!
+//! Patterns are evaluated in closed form from the number of fixed ticks since the entity
+//! spawned, so a run plays out the same way regardless of frame rate. Distances are in world
+//! units, durations in seconds and speeds in world units per second.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{Movement, Player};
+
+#[derive(Deserialize, Clone, Debug)]
+pub enum Pattern {
+    /// Fall while strafing from side to side.
+    Sine {
+        speed: f32,
+        amplitude: f32,
+        /// Full strafes per second.
+        frequency: f32,
+    },
+    /// Circle around a center which starts `radius` to the left of the spawn point and
+    /// sinks at `descent`.
+    Orbit {
+        radius: f32,
+        /// Radians per second, counterclockwise when positive.
+        angular_speed: f32,
+        #[serde(default)]
+        descent: f32,
+    },
+    /// Follow a curve through points relative to the spawn point, then stay at the end.
+    Path {
+        points: Vec<(f32, f32)>,
+        duration: f32,
+        #[serde(default)]
+        curve: Curve,
+    },
+    /// Wait, then dive in a straight line towards where the player was at that moment.
+    Dive { delay: f32, speed: f32 },
+    /// Move to an offset from the spawn point, stay there, then fly back up.
+    HoldThenRetreat {
+        offset: (f32, f32),
+        enter: f32,
+        hold: f32,
+        retreat_speed: f32,
+    },
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub enum Curve {
+    /// A single Bézier curve using the points as control points. Only passes through the
+    /// first and last point.
+    Bezier,
+    /// A Catmull-Rom spline passing through every point.
+    #[default]
+    CatmullRom,
+}
+
+#[derive(Component, Clone)]
+pub struct MovementPattern {
+    pattern: Pattern,
+    origin: Vec2,
+    ticks: u32,
+    /// The direction of a dive, chosen when it starts.
+    dive: Option<Vec2>,
+}
+
+impl MovementPattern {
+    pub fn new(pattern: Pattern, origin: Vec2) -> Self {
+        Self {
+            pattern,
+            origin,
+            ticks: 0,
+            dive: None,
+        }
+    }
+
+    /// The offset from the origin after the given number of seconds.
+    fn offset(&self, t: f32) -> Vec2 {
+        match &self.pattern {
+            Pattern::Sine {
+                speed,
+                amplitude,
+                frequency,
+            } => Vec2::new(amplitude * (TAU * frequency * t).sin(), -speed * t),
+            Pattern::Orbit {
+                radius,
+                angular_speed,
+                descent,
+            } => {
+                let angle = angular_speed * t;
+
+                Vec2::new(
+                    radius * (angle.cos() - 1.0),
+                    radius * angle.sin() - descent * t,
+                )
+            }
+            Pattern::Path {
+                points,
+                duration,
+                curve,
+            } => {
+                let u = if *duration > 0.0 {
+                    (t / duration).min(1.0)
+                } else {
+                    1.0
+                };
+
+                match curve {
+                    Curve::Bezier => bezier(points, u),
+                    Curve::CatmullRom => catmull_rom(points, u),
+                }
+            }
+            Pattern::Dive { delay, speed } => match self.dive {
+                Some(direction) if t > *delay => direction * *speed * (t - delay),
+                _ => Vec2::ZERO,
+            },
+            Pattern::HoldThenRetreat {
+                offset,
+                enter,
+                hold,
+                retreat_speed,
+            } => {
+                let offset = Vec2::from(*offset);
+
+                if t < *enter {
+                    let u = t / enter;
+
+                    offset * u * u * (3.0 - 2.0 * u)
+                } else {
+                    offset + Vec2::new(0.0, retreat_speed * (t - enter - hold).max(0.0))
+                }
+            }
+        }
+    }
+}
+
+pub fn update_patterns(
+    time: Res<FixedTime>,
+    query_player: Query<&Transform, (With<Player>, Without<MovementPattern>)>,
+    mut query: Query<(&mut MovementPattern, &mut Transform, &mut Movement)>,
+) {
+    let period = time.period.as_secs_f32();
+
+    for (mut pattern, mut transform, mut movement) in &mut query {
+        pattern.ticks += 1;
+        let t = pattern.ticks as f32 * period;
+
+        if let Pattern::Dive { delay, .. } = pattern.pattern {
+            if pattern.dive.is_none() && t > delay {
+                let target = match query_player.get_single() {
+                    Ok(player) => player.translation.truncate(),
+                    Err(_) => pattern.origin - Vec2::Y,
+                };
+
+                pattern.dive = Some((target - pattern.origin).normalize_or_zero());
+            }
+        }
+
+        let position = pattern.origin + pattern.offset(t);
+
+        movement.velocity = position - transform.translation.truncate();
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+fn bezier(points: &[(f32, f32)], u: f32) -> Vec2 {
+    if points.is_empty() {
+        return Vec2::ZERO;
+    }
+
+    //Bernstein form, which needs no scratch space unlike De Casteljau
+    let n = points.len() - 1;
+    let mut binomial = 1.0;
+    let mut sum = Vec2::ZERO;
+
+    for (i, &point) in points.iter().enumerate() {
+        let weight = binomial * u.powi(i as i32) * (1.0 - u).powi((n - i) as i32);
+        sum += Vec2::from(point) * weight;
+
+        binomial = binomial * (n - i) as f32 / (i + 1) as f32;
+    }
+
+    sum
+}
+
+fn catmull_rom(points: &[(f32, f32)], u: f32) -> Vec2 {
+    if points.is_empty() {
+        return Vec2::ZERO;
+    }
+
+    let segments = points.len() - 1;
+    if segments == 0 {
+        return Vec2::from(points[0]);
+    }
+
+    let scaled = u * segments as f32;
+    let i = (scaled as usize).min(segments - 1);
+    let s = scaled - i as f32;
+
+    //The end points are repeated so the curve starts and stops on them
+    let point = |index: isize| Vec2::from(points[index.clamp(0, segments as isize) as usize]);
+    let (p0, p1, p2, p3) = (
+        point(i as isize - 1),
+        point(i as isize),
+        point(i as isize + 1),
+        point(i as isize + 2),
+    );
+
+    0.5 * (2.0 * p1
+        + (p2 - p0) * s
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * s * s
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * s * s * s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::*;
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-4, "{a} is not {b}");
+    }
+
+    fn offset(pattern: Pattern, t: f32) -> Vec2 {
+        MovementPattern::new(pattern, Vec2::ZERO).offset(t)
+    }
+
+    #[test]
+    fn sine_strafes_while_falling() {
+        let sine = Pattern::Sine {
+            speed: 10.0,
+            amplitude: 20.0,
+            frequency: 0.5,
+        };
+
+        assert_near(offset(sine.clone(), 0.0), Vec2::ZERO);
+        assert_near(offset(sine.clone(), 0.5), Vec2::new(20.0, -5.0));
+        assert_near(offset(sine, 1.0), Vec2::new(0.0, -10.0));
+    }
+
+    #[test]
+    fn orbit_starts_at_the_origin() {
+        let orbit = Pattern::Orbit {
+            radius: 10.0,
+            angular_speed: PI,
+            descent: 2.0,
+        };
+
+        assert_near(offset(orbit.clone(), 0.0), Vec2::ZERO);
+        assert_near(offset(orbit, 1.0), Vec2::new(-20.0, -2.0));
+    }
+
+    #[test]
+    fn path_runs_from_the_first_point_to_the_last() {
+        let points = vec![(0.0, 0.0), (10.0, 5.0), (20.0, 0.0)];
+
+        for curve in [Curve::Bezier, Curve::CatmullRom] {
+            let path = Pattern::Path {
+                points: points.clone(),
+                duration: 2.0,
+                curve,
+            };
+
+            assert_near(offset(path.clone(), 0.0), Vec2::ZERO);
+            assert_near(offset(path.clone(), 2.0), Vec2::new(20.0, 0.0));
+            assert_near(offset(path, 5.0), Vec2::new(20.0, 0.0));
+        }
+
+        let instant = Pattern::Path {
+            points,
+            duration: 0.0,
+            curve: Curve::CatmullRom,
+        };
+
+        assert_near(offset(instant, 0.0), Vec2::new(20.0, 0.0));
+    }
+
+    #[test]
+    fn dive_waits_for_a_direction() {
+        let mut dive = MovementPattern::new(
+            Pattern::Dive {
+                delay: 1.0,
+                speed: 10.0,
+            },
+            Vec2::ZERO,
+        );
+
+        assert_near(dive.offset(2.0), Vec2::ZERO);
+
+        dive.dive = Some(Vec2::NEG_Y);
+
+        assert_near(dive.offset(0.5), Vec2::ZERO);
+        assert_near(dive.offset(2.0), Vec2::new(0.0, -10.0));
+    }
+
+    #[test]
+    fn hold_then_retreat() {
+        let pattern = Pattern::HoldThenRetreat {
+            offset: (0.0, -50.0),
+            enter: 1.0,
+            hold: 2.0,
+            retreat_speed: 20.0,
+        };
+
+        assert_near(offset(pattern.clone(), 0.0), Vec2::ZERO);
+        assert_near(offset(pattern.clone(), 0.5), Vec2::new(0.0, -25.0));
+        assert_near(offset(pattern.clone(), 1.0), Vec2::new(0.0, -50.0));
+        assert_near(offset(pattern.clone(), 3.0), Vec2::new(0.0, -50.0));
+        assert_near(offset(pattern, 4.0), Vec2::new(0.0, -30.0));
+    }
+
+    #[test]
+    fn bezier_endpoints_and_midpoint() {
+        let points = [(0.0, 0.0), (1.0, 2.0), (2.0, 0.0)];
+
+        assert_near(bezier(&points, 0.0), Vec2::ZERO);
+        assert_near(bezier(&points, 0.5), Vec2::new(1.0, 1.0));
+        assert_near(bezier(&points, 1.0), Vec2::new(2.0, 0.0));
+
+        let cubic = [(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0)];
+
+        assert_near(bezier(&cubic, 0.5), Vec2::new(1.5, 2.25));
+    }
+
+    #[test]
+    fn bezier_with_few_points() {
+        assert_eq!(bezier(&[], 0.5), Vec2::ZERO);
+        assert_near(bezier(&[(3.0, 4.0)], 0.5), Vec2::new(3.0, 4.0));
+        assert_near(bezier(&[(0.0, 0.0), (4.0, 2.0)], 0.25), Vec2::new(1.0, 0.5));
+    }
+
+    #[test]
+    fn catmull_rom_passes_through_every_point() {
+        let points = [(0.0, 0.0), (10.0, 5.0), (20.0, 0.0), (30.0, 10.0)];
+
+        for (i, &point) in points.iter().enumerate() {
+            assert_near(catmull_rom(&points, i as f32 / 3.0), Vec2::from(point));
+        }
+    }
+
+    #[test]
+    fn catmull_rom_with_few_points() {
+        assert_eq!(catmull_rom(&[], 0.5), Vec2::ZERO);
+        assert_near(catmull_rom(&[(3.0, 4.0)], 0.5), Vec2::new(3.0, 4.0));
+        assert_near(
+            catmull_rom(&[(0.0, 0.0), (4.0, 2.0)], 0.5),
+            Vec2::new(2.0, 1.0),
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Power-ups dropped by destroyed enemies. Pickups drift down the screen, are pulled in once
+//! they come within the player's magnet radius, and take effect when they touch the ship.
+//!
+//! What each enemy kind drops is set in `enemies.drops.ron`: the chance of dropping anything
+//! at all, and the relative weight of each pickup kind. Bosses carry their own drop table in
+//! their definition.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use rand::{thread_rng, Rng};
+use sepax::prelude::{sat_overlap, signed_distance};
+use serde::Deserialize;
+
+use crate::boss::BossKilled;
+use crate::emitter::EnemyBullet;
+use crate::health::{Lives, Shield};
+use crate::ron_asset::RonLoader;
+use crate::score::{Combo, EnemyKilled, Score, ScoreEvent};
+use crate::state::GameplaySet;
+use crate::weapon::{Weapon, WeaponDef, WeaponLibrary};
+use crate::{hitbox, AutoDespawn, Enemy, EnemyKind, Lifetime, Movement, Player, AABB};
+
+const DROP_TABLES: &str = "enemies.drops.ron";
+
+const GEM_POINTS: usize = 500;
+/// How fast pickups fly towards the player once caught by the magnet, per tick.
+const MAGNET_SPEED: f32 = 4.0;
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PickupKind {
+    WeaponUpgrade,
+    Shield,
+    ExtraLife,
+    ScoreGem,
+    /// Destroys every enemy and enemy bullet.
+    Bomb,
+}
+
+impl PickupKind {
+    fn color(&self) -> Color {
+        match self {
+            PickupKind::WeaponUpgrade => Color::rgb(1.0, 0.6, 0.1),
+            PickupKind::Shield => Color::rgb(0.3, 0.6, 1.0),
+            PickupKind::ExtraLife => Color::rgb(0.3, 1.0, 0.4),
+            PickupKind::ScoreGem => Color::rgb(1.0, 1.0, 0.3),
+            PickupKind::Bomb => Color::rgb(1.0, 0.2, 0.8),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct DropTable {
+    /// The chance of dropping anything, from 0 to 1.
+    pub chance: f32,
+    /// The pickups to choose from, each with its relative weight.
+    pub drops: Vec<(PickupKind, u32)>,
+}
+
+impl DropTable {
+    fn roll(&self, rng: &mut impl Rng) -> Option<PickupKind> {
+        let total: u32 = self.drops.iter().map(|(_, weight)| weight).sum();
+
+        if total == 0 || rng.gen::<f32>() >= self.chance {
+            return None;
+        }
+
+        let mut pick = rng.gen_range(0..total);
+        for (kind, weight) in &self.drops {
+            if pick < *weight {
+                return Some(*kind);
+            }
+            pick -= weight;
+        }
+
+        None
+    }
+}
+
+#[derive(Deserialize, TypeUuid, TypePath)]
+#[uuid = "c41e8b27-95d3-4f0a-b6e2-7a1d3c9f5e48"]
+pub struct DropTables(pub HashMap<EnemyKind, DropTable>);
+
+#[derive(Resource)]
+struct DropTablesHandle(Handle<DropTables>);
+
+impl FromWorld for DropTablesHandle {
+    fn from_world(world: &mut World) -> Self {
+        Self(world.resource::<AssetServer>().load(DROP_TABLES))
+    }
+}
+
+#[derive(Component)]
+pub struct Pickup {
+    pub kind: PickupKind,
+}
+
+/// Pulls in pickups whose center comes within `radius` of the edge of the ship's hitbox.
+#[derive(Component)]
+pub struct Magnet {
+    pub radius: f32,
+}
+
+/// Sent when the player collects a pickup, after its effect is applied.
+#[derive(Event, Clone, Copy)]
+pub struct PickupCollected {
+    pub kind: PickupKind,
+}
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<DropTables>()
+            .add_asset_loader(RonLoader::<DropTables>::new(&["drops.ron"]))
+            .init_resource::<DropTablesHandle>()
+            .add_event::<PickupCollected>()
+            .add_systems(
+                FixedUpdate,
+                //A bomb kills enemies when it is collected, which must come before their drops
+                (attract_pickups, collect_pickups, drop_pickups)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .after(crate::check_collisions),
+            );
+    }
+}
+
+fn drop_pickups(
+    mut commands: Commands,
+    mut enemy_killed: EventReader<EnemyKilled>,
+    mut boss_killed: EventReader<BossKilled>,
+    handle: Res<DropTablesHandle>,
+    tables: Res<Assets<DropTables>>,
+) {
+    let mut rng = thread_rng();
+
+    for kill in boss_killed.iter() {
+        if let Some(kind) = kill.drops.as_ref().and_then(|table| table.roll(&mut rng)) {
+            spawn_pickup(&mut commands, kind, kill.position);
+        }
+    }
+
+    let Some(tables) = tables.get(&handle.0) else {
+        enemy_killed.clear();
+        return;
+    };
+
+    for kill in enemy_killed.iter() {
+        if let Some(kind) = tables
+            .0
+            .get(&kill.kind)
+            .and_then(|table| table.roll(&mut rng))
+        {
+            spawn_pickup(&mut commands, kind, kill.position);
+        }
+    }
+}
+
+fn spawn_pickup(commands: &mut Commands, kind: PickupKind, position: Vec2) {
+    commands.spawn((
+        Pickup { kind },
+        AutoDespawn,
+        SpriteBundle {
+            sprite: Sprite {
+                color: kind.color(),
+                custom_size: Some(Vec2::splat(6.0)),
+                ..default()
+            },
+            transform: Transform::from_xyz(position.x, position.y, 1.0),
+            ..default()
+        },
+        AABB {
+            half_size: Vec2::splat(3.0),
+        },
+        Movement {
+            acceleration: Vec2::ZERO,
+            velocity: Vec2::new(0.0, -0.5),
+            damping: 0.0,
+            max_speed: MAGNET_SPEED,
+        },
+        Lifetime::from_seconds(8.0),
+    ));
+}
+
+fn attract_pickups(
+    query_player: Query<(&Transform, &AABB, &Magnet), With<Player>>,
+    mut query_pickup: Query<(&Transform, &mut Movement), (With<Pickup>, Without<Player>)>,
+) {
+    for (transform, aabb, magnet) in &query_player {
+        let player = hitbox(transform, aabb);
+        let center = transform.translation.truncate();
+
+        for (transform_pickup, mut movement) in &mut query_pickup {
+            let position = transform_pickup.translation.truncate();
+
+            if signed_distance(&player, (position.x, position.y)) < magnet.radius {
+                movement.velocity = (center - position).normalize_or_zero() * MAGNET_SPEED;
+            }
+        }
+    }
+}
+
+pub fn collect_pickups(
+    mut commands: Commands,
+    mut collected: EventWriter<PickupCollected>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut enemy_killed: EventWriter<EnemyKilled>,
+    mut score: ResMut<Score>,
+    combo: Res<Combo>,
+    library: Res<WeaponLibrary>,
+    weapons: Res<Assets<WeaponDef>>,
+    mut query_player: Query<(Entity, &Transform, &AABB, &mut Lives, &mut Weapon), With<Player>>,
+    query_pickup: Query<(Entity, &Pickup, &Transform, &AABB), Without<Player>>,
+    query_enemy: Query<(Entity, &Enemy, &Transform), Without<Player>>,
+    query_enemy_bullet: Query<Entity, With<EnemyBullet>>,
+) {
+    for (entity, transform, aabb, mut lives, mut weapon) in &mut query_player {
+        let player = hitbox(transform, aabb);
+
+        for (entity_pickup, pickup, transform_pickup, aabb_pickup) in &query_pickup {
+            if !sat_overlap(&player, &hitbox(transform_pickup, aabb_pickup)) {
+                continue;
+            }
+
+            commands.entity(entity_pickup).despawn_recursive();
+
+            match pickup.kind {
+                PickupKind::WeaponUpgrade => {
+                    let max_level = library
+                        .weapons
+                        .get(weapon.weapon)
+                        .and_then(|handle| weapons.get(handle))
+                        .map_or(0, |def| def.max_level());
+
+                    weapon.upgrade(max_level);
+                }
+                PickupKind::Shield => {
+                    commands.entity(entity).insert(Shield);
+                }
+                PickupKind::ExtraLife => {
+                    lives.0 += 1;
+                }
+                PickupKind::ScoreGem => {
+                    score.0 += GEM_POINTS;
+
+                    score_events.send(ScoreEvent {
+                        points: GEM_POINTS,
+                        total: score.0,
+                        position: transform_pickup.translation.truncate(),
+                        chain: combo.chain,
+                        multiplier: 1.0,
+                    });
+                }
+                PickupKind::Bomb => {
+                    for (entity_enemy, enemy, transform_enemy) in &query_enemy {
+                        commands.entity(entity_enemy).despawn_recursive();
+
+                        enemy_killed.send(EnemyKilled {
+                            kind: enemy.kind,
+                            position: transform_enemy.translation.truncate(),
+                            bounces: 0,
+                        });
+                    }
+
+                    for entity_bullet in &query_enemy_bullet {
+                        commands.entity(entity_bullet).despawn_recursive();
+                    }
+                }
+            }
+
+            collected.send(PickupCollected { kind: pickup.kind });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn rolls(table: &DropTable, count: usize) -> Vec<Option<PickupKind>> {
+        let mut rng = StdRng::seed_from_u64(49);
+
+        (0..count).map(|_| table.roll(&mut rng)).collect()
+    }
+
+    #[test]
+    fn no_chance_never_drops() {
+        let table = DropTable {
+            chance: 0.0,
+            drops: vec![(PickupKind::Shield, 1)],
+        };
+
+        assert!(rolls(&table, 1000).iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn no_weight_never_drops() {
+        let weightless = DropTable {
+            chance: 1.0,
+            drops: vec![(PickupKind::Shield, 0)],
+        };
+        let empty = DropTable {
+            chance: 1.0,
+            drops: Vec::new(),
+        };
+
+        assert!(rolls(&weightless, 1000).iter().all(Option::is_none));
+        assert!(rolls(&empty, 1000).iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn drops_follow_their_weights() {
+        let table = DropTable {
+            chance: 1.0,
+            drops: vec![
+                (PickupKind::ScoreGem, 3),
+                (PickupKind::Bomb, 0),
+                (PickupKind::Shield, 1),
+            ],
+        };
+        let rolls = rolls(&table, 4000);
+        let count = |kind| rolls.iter().filter(|&&roll| roll == Some(kind)).count();
+
+        assert_eq!(
+            count(PickupKind::ScoreGem) + count(PickupKind::Shield),
+            4000
+        );
+        assert_eq!(count(PickupKind::Bomb), 0);
+        assert!((2800..3200).contains(&count(PickupKind::ScoreGem)));
+    }
+
+    #[test]
+    fn chance_limits_drops() {
+        let table = DropTable {
+            chance: 0.25,
+            drops: vec![(PickupKind::ExtraLife, 1)],
+        };
+        let drops = rolls(&table, 4000)
+            .iter()
+            .filter(|roll| roll.is_some())
+            .count();
+
+        assert!((800..1200).contains(&drops));
+    }
+}
@@ -0,0 +1,213 @@
+//! Points for destroyed enemies, with a combo chain for quick successive kills and a bonus
+//! for kills made with bullets that bounced off a mirror.
+//!
+//! Gameplay systems report kills with `EnemyKilled`, and defeated bosses with `BossKilled`.
+//! Everything that wants to react to the score, such as the HUD, audio or achievements, should
+//! read `ScoreEvent` and `ComboEnded` instead of polling the `Score` resource.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::boss::BossKilled;
+use crate::state::GameplaySet;
+use crate::EnemyKind;
+
+/// How long a combo chain survives without another kill.
+const COMBO_WINDOW: f32 = 2.0;
+/// Extra multiplier for every kill in a chain after the first.
+const COMBO_STEP: f32 = 0.1;
+const MAX_COMBO_MULTIPLIER: f32 = 3.0;
+/// Extra multiplier for every mirror a bullet bounced off before the kill.
+const REFLECT_STEP: f32 = 1.0;
+
+#[derive(Resource, Default)]
+pub struct Score(pub usize);
+
+/// The current chain of kills, each made within `COMBO_WINDOW` of the previous one.
+#[derive(Resource)]
+pub struct Combo {
+    pub chain: usize,
+    timer: Timer,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Self {
+            chain: 0,
+            timer: Timer::from_seconds(COMBO_WINDOW, TimerMode::Once),
+        }
+    }
+}
+
+impl Combo {
+    pub fn multiplier(&self) -> f32 {
+        let extra = self.chain.saturating_sub(1) as f32 * COMBO_STEP;
+
+        (1.0 + extra).min(MAX_COMBO_MULTIPLIER)
+    }
+
+    /// Adds a kill to the chain and restarts the window.
+    fn extend(&mut self) {
+        self.chain += 1;
+        self.timer.reset();
+    }
+
+    /// Advances the window, returning the length of the chain if it just ran out.
+    fn tick(&mut self, delta: Duration) -> Option<usize> {
+        if self.chain == 0 || !self.timer.tick(delta).just_finished() {
+            return None;
+        }
+
+        Some(std::mem::take(&mut self.chain))
+    }
+}
+
+/// Sent by gameplay systems when an enemy is destroyed by the player.
+#[derive(Event, Clone, Copy)]
+pub struct EnemyKilled {
+    pub kind: EnemyKind,
+    pub position: Vec2,
+    /// The number of mirrors the killing bullet bounced off.
+    pub bounces: u32,
+}
+
+/// Sent whenever points are awarded.
+#[derive(Event, Clone, Copy)]
+pub struct ScoreEvent {
+    pub points: usize,
+    pub total: usize,
+    pub position: Vec2,
+    pub chain: usize,
+    pub multiplier: f32,
+}
+
+/// Sent when a combo chain times out.
+#[derive(Event, Clone, Copy)]
+pub struct ComboEnded {
+    pub chain: usize,
+}
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .init_resource::<Combo>()
+            .add_event::<EnemyKilled>()
+            .add_event::<ScoreEvent>()
+            .add_event::<ComboEnded>()
+            .add_systems(
+                FixedUpdate,
+                (tick_combo, award_points)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .after(crate::check_collisions)
+                    .after(crate::pickup::collect_pickups),
+            );
+    }
+}
+
+fn tick_combo(
+    time: Res<FixedTime>,
+    mut combo: ResMut<Combo>,
+    mut combo_ended: EventWriter<ComboEnded>,
+) {
+    if let Some(chain) = combo.tick(time.period) {
+        combo_ended.send(ComboEnded { chain });
+    }
+}
+
+fn award_points(
+    mut score: ResMut<Score>,
+    mut combo: ResMut<Combo>,
+    mut enemy_killed: EventReader<EnemyKilled>,
+    mut boss_killed: EventReader<BossKilled>,
+    mut score_events: EventWriter<ScoreEvent>,
+) {
+    let kills = enemy_killed
+        .iter()
+        .map(|kill| (kill.kind.points(), kill.bounces, kill.position))
+        .chain(
+            boss_killed
+                .iter()
+                .map(|kill| (kill.points, 0, kill.position)),
+        );
+
+    for (base, bounces, position) in kills {
+        combo.extend();
+
+        let multiplier = combo.multiplier() * (1.0 + bounces as f32 * REFLECT_STEP);
+        let points = (base as f32 * multiplier).round() as usize;
+
+        score.0 += points;
+
+        score_events.send(ScoreEvent {
+            points,
+            total: score.0,
+            position,
+            chain: combo.chain,
+            multiplier,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(kills: usize) -> Combo {
+        let mut combo = Combo::default();
+        for _ in 0..kills {
+            combo.extend();
+        }
+
+        combo
+    }
+
+    /// A fraction of the combo window.
+    fn window(fraction: f32) -> Duration {
+        Duration::from_secs_f32(COMBO_WINDOW * fraction)
+    }
+
+    #[test]
+    fn multiplier_grows_with_the_chain() {
+        assert_eq!(chain(0).multiplier(), 1.0);
+        assert_eq!(chain(1).multiplier(), 1.0);
+        assert!((chain(2).multiplier() - 1.1).abs() < 1e-6);
+        assert!((chain(11).multiplier() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn multiplier_is_capped() {
+        assert_eq!(chain(21).multiplier(), MAX_COMBO_MULTIPLIER);
+        assert_eq!(chain(100).multiplier(), MAX_COMBO_MULTIPLIER);
+    }
+
+    #[test]
+    fn chain_ends_after_the_window() {
+        let mut combo = chain(3);
+
+        assert_eq!(combo.tick(window(0.75)), None);
+        assert_eq!(combo.chain, 3);
+        assert_eq!(combo.tick(window(0.5)), Some(3));
+        assert_eq!(combo.chain, 0);
+    }
+
+    #[test]
+    fn kills_restart_the_window() {
+        let mut combo = chain(1);
+
+        assert_eq!(combo.tick(window(0.75)), None);
+        combo.extend();
+        assert_eq!(combo.tick(window(0.75)), None);
+        assert_eq!(combo.chain, 2);
+    }
+
+    #[test]
+    fn empty_chain_never_ends() {
+        let mut combo = Combo::default();
+
+        assert_eq!(combo.tick(window(2.0)), None);
+    }
+}
@@ -0,0 +1,142 @@
+//! The flow of a run: title screen, playing, paused and game over.
+//!
+//! Gameplay systems in `FixedUpdate` belong in `GameplaySet`, which only runs while
+//! `GameState::Playing`, so pausing or dying freezes the world in place. Entering the title
+//! screen clears out the previous run, and leaving it spawns a fresh one.
+
+use bevy::prelude::*;
+
+use crate::boss::{Boss, PendingBoss};
+use crate::emitter::EnemyBullet;
+use crate::interpolation::InterpolationSet;
+use crate::mirror::{Mirror, MirrorSpawner};
+use crate::pickup::Pickup;
+use crate::score::{Combo, Score};
+use crate::{Bullet, Enemy, Player};
+
+#[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GameState {
+    #[default]
+    Title,
+    Playing,
+    Paused,
+    GameOver,
+}
+
+/// Systems which simulate the game and must stop while it is not being played. The set runs
+/// between `InterpolationSet::Restore` and `InterpolationSet::Record`, so its systems always
+/// see and write the simulated `Transform`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameplaySet;
+
+/// Text shown over the game for the state it was spawned in.
+#[derive(Component)]
+struct StateText;
+
+pub struct StatePlugin;
+
+impl Plugin for StatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_state::<GameState>()
+            .configure_sets(
+                FixedUpdate,
+                GameplaySet
+                    .run_if(in_state(GameState::Playing))
+                    .after(InterpolationSet::Restore)
+                    .before(InterpolationSet::Record),
+            )
+            .add_systems(
+                OnEnter(GameState::Title),
+                (
+                    despawn_all::<Player>,
+                    despawn_all::<Enemy>,
+                    despawn_all::<Bullet>,
+                    despawn_all::<EnemyBullet>,
+                    despawn_all::<Pickup>,
+                    despawn_all::<Mirror>,
+                    despawn_all::<MirrorSpawner>,
+                    despawn_all::<Boss>,
+                    despawn_all::<PendingBoss>,
+                    reset_score,
+                    spawn_state_text("BLASTEX\n\nPress Enter to start"),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::Paused),
+                spawn_state_text("Paused\n\nPress Escape to resume\nPress R to restart"),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                spawn_state_text("Game over\n\nPress Enter to continue"),
+            )
+            .add_systems(OnExit(GameState::Title), despawn_all::<StateText>)
+            .add_systems(OnExit(GameState::Paused), despawn_all::<StateText>)
+            .add_systems(OnExit(GameState::GameOver), despawn_all::<StateText>)
+            .add_systems(Update, change_state);
+    }
+}
+
+fn change_state(
+    keys: Res<Input<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    match state.get() {
+        GameState::Title => {
+            if keys.just_pressed(KeyCode::Return) {
+                next_state.set(GameState::Playing);
+            }
+        }
+        GameState::Playing => {
+            if keys.just_pressed(KeyCode::Escape) {
+                next_state.set(GameState::Paused);
+            }
+        }
+        GameState::Paused => {
+            if keys.just_pressed(KeyCode::Escape) {
+                next_state.set(GameState::Playing);
+            } else if keys.just_pressed(KeyCode::R) {
+                next_state.set(GameState::Title);
+            }
+        }
+        GameState::GameOver => {
+            if keys.just_pressed(KeyCode::Return) {
+                next_state.set(GameState::Title);
+            }
+        }
+    }
+}
+
+pub fn despawn_all<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn reset_score(mut score: ResMut<Score>, mut combo: ResMut<Combo>) {
+    score.0 = 0;
+    *combo = Combo::default();
+}
+
+fn spawn_state_text(message: &'static str) -> impl Fn(Commands, Res<AssetServer>) {
+    move |mut commands: Commands, asset_server: Res<AssetServer>| {
+        commands.spawn((
+            StateText,
+            TextBundle::from_section(
+                message,
+                TextStyle {
+                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                    font_size: 32.0,
+                    color: Color::WHITE,
+                },
+            )
+            .with_text_alignment(TextAlignment::Center)
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(40.0),
+                left: Val::Percent(35.0),
+                ..default()
+            }),
+        ));
+    }
+}
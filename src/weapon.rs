@@ -0,0 +1,246 @@
+//! The player's weapons, defined in `.weapon.ron` asset files. A weapon has several upgrade
+//! levels, each with its own fire-rate cooldown and list of shots:
+//!
+//! ```ron
+//! (
+//!     name: "Spread",
+//!     levels: [
+//!         (cooldown: 0.25, shots: [(direction: (0.0, 1.0), count: 3, spread: 0.4)]),
+//!         (cooldown: 0.2, shots: [(direction: (0.0, 1.0), count: 5, spread: 0.6, damage: 1.5)]),
+//!     ],
+//! )
+//! ```
+//!
+//! Directions are vectors, spreads in radians, cooldowns in seconds and speeds in world units
+//! per second. Offsets are relative to the player's center. Damage is taken from the health of
+//! whatever a bullet hits: scouts go down to a single default shot, while heavies and boss
+//! parts take several.
+
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use serde::Deserialize;
+
+use crate::ron_asset::RonLoader;
+use crate::state::GameState;
+use crate::{spawn_bullet, Player};
+
+/// The weapons the player can cycle through, in order. The first one is equipped at the
+/// start of a run.
+const WEAPONS: [&str; 2] = ["weapons/twin.weapon.ron", "weapons/spread.weapon.ron"];
+
+#[derive(Deserialize, TypeUuid, TypePath)]
+#[uuid = "8d2f6a41-7c3e-4b59-a0d1-6e9b3f5c2a87"]
+pub struct WeaponDef {
+    pub name: String,
+    pub levels: Vec<WeaponLevel>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WeaponLevel {
+    pub cooldown: f32,
+    pub shots: Vec<ShotDef>,
+}
+
+/// One or more bullets fired together, spread evenly over `spread` radians around
+/// `direction`.
+#[derive(Deserialize, Clone, Copy)]
+pub struct ShotDef {
+    #[serde(default)]
+    pub offset: (f32, f32),
+    pub direction: (f32, f32),
+    #[serde(default = "one")]
+    pub count: usize,
+    #[serde(default)]
+    pub spread: f32,
+    #[serde(default = "default_speed")]
+    pub speed: f32,
+    /// Taken from the health of the enemy or boss part each bullet hits.
+    #[serde(default = "default_damage")]
+    pub damage: f32,
+}
+
+fn one() -> usize {
+    1
+}
+
+fn default_speed() -> f32 {
+    180.0
+}
+
+fn default_damage() -> f32 {
+    1.0
+}
+
+#[derive(Resource)]
+pub struct WeaponLibrary {
+    pub weapons: Vec<Handle<WeaponDef>>,
+}
+
+impl FromWorld for WeaponLibrary {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+
+        Self {
+            weapons: WEAPONS
+                .iter()
+                .map(|path| asset_server.load(*path))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Weapon {
+    pub weapon: usize,
+    /// The upgrade level, counting from zero. Levels past the last one defined by the weapon
+    /// fire like the last one.
+    pub level: usize,
+    /// Seconds until the weapon can fire again.
+    cooldown: f32,
+}
+
+impl Weapon {
+    pub fn new(weapon: usize) -> Self {
+        Self {
+            weapon,
+            level: 0,
+            cooldown: 0.0,
+        }
+    }
+
+    pub fn upgrade(&mut self, max_level: usize) {
+        self.level = (self.level + 1).min(max_level);
+    }
+}
+
+impl WeaponDef {
+    fn level(&self, level: usize) -> Option<&WeaponLevel> {
+        self.levels.get(level).or(self.levels.last())
+    }
+
+    pub fn max_level(&self) -> usize {
+        self.levels.len().saturating_sub(1)
+    }
+}
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<WeaponDef>()
+            .add_asset_loader(RonLoader::<WeaponDef>::new(&["weapon.ron"]))
+            .init_resource::<WeaponLibrary>()
+            .add_systems(Update, cycle_weapons.run_if(in_state(GameState::Playing)));
+    }
+}
+
+pub fn fire_weapons(
+    keys: Res<Input<KeyCode>>,
+    time: Res<FixedTime>,
+    mut commands: Commands,
+    library: Res<WeaponLibrary>,
+    weapons: Res<Assets<WeaponDef>>,
+    mut query: Query<(&Transform, &mut Weapon), With<Player>>,
+) {
+    let period = time.period.as_secs_f32();
+
+    for (transform, mut weapon) in &mut query {
+        weapon.cooldown = (weapon.cooldown - period).max(0.0);
+
+        if !keys.pressed(KeyCode::Space) || weapon.cooldown > 0.0 {
+            continue;
+        }
+
+        let Some(level) = library
+            .weapons
+            .get(weapon.weapon)
+            .and_then(|handle| weapons.get(handle))
+            .and_then(|def| def.level(weapon.level))
+        else {
+            continue;
+        };
+
+        let position = transform.translation.truncate();
+
+        for shot in &level.shots {
+            let direction = Vec2::from(shot.direction).normalize_or_zero();
+            let start = position + Vec2::from(shot.offset);
+
+            for i in 0..shot.count {
+                let angle = if shot.count > 1 {
+                    -shot.spread / 2.0 + shot.spread * i as f32 / (shot.count - 1) as f32
+                } else {
+                    0.0
+                };
+                let velocity = Vec2::from_angle(angle).rotate(direction) * shot.speed * period;
+
+                spawn_bullet(&mut commands, start, velocity, shot.damage);
+            }
+        }
+
+        weapon.cooldown = level.cooldown;
+    }
+}
+
+fn cycle_weapons(
+    keys: Res<Input<KeyCode>>,
+    library: Res<WeaponLibrary>,
+    mut query: Query<&mut Weapon, With<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::Q) || library.weapons.is_empty() {
+        return;
+    }
+
+    for mut weapon in &mut query {
+        weapon.weapon = (weapon.weapon + 1) % library.weapons.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weapon(cooldowns: &[f32]) -> WeaponDef {
+        WeaponDef {
+            name: "Test".to_string(),
+            levels: cooldowns
+                .iter()
+                .map(|&cooldown| WeaponLevel {
+                    cooldown,
+                    shots: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn levels_past_the_last_fire_like_the_last() {
+        let def = weapon(&[0.3, 0.2, 0.1]);
+
+        assert_eq!(def.level(0).map(|level| level.cooldown), Some(0.3));
+        assert_eq!(def.level(2).map(|level| level.cooldown), Some(0.1));
+        assert_eq!(def.level(7).map(|level| level.cooldown), Some(0.1));
+    }
+
+    #[test]
+    fn weapon_without_levels_cannot_fire() {
+        let def = weapon(&[]);
+
+        assert!(def.level(0).is_none());
+        assert_eq!(def.max_level(), 0);
+    }
+
+    #[test]
+    fn upgrades_stop_at_the_max_level() {
+        let def = weapon(&[0.3, 0.2]);
+        let mut equipped = Weapon::new(0);
+
+        assert_eq!(def.max_level(), 1);
+
+        equipped.upgrade(def.max_level());
+        assert_eq!(equipped.level, 1);
+
+        equipped.upgrade(def.max_level());
+        assert_eq!(equipped.level, 1);
+    }
+}
@@ -1,3 +1,3 @@
 #!/usr/bin/env bash
 
-cargo watch -w src -w assets -x run
+cargo watch -w src -w sepax/src -w assets -x run